@@ -0,0 +1,96 @@
+//! DEX client for seeding liquidity after a successful launch.
+//!
+//! Speaks a raw SCALE protocol to a router-style DEX that keeps one pool per
+//! `(token, quote)` pair, where `quote: None` stands for native VARA.
+//! `AddLiquidity` pulls both sides with `transfer_from` (native VARA travels as
+//! message value) and replies with the liquidity minted to `to`.
+
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sails_rs::prelude::*;
+use vara_contracts_shared::ContractError;
+use crate::vft_client::U256;
+
+// =============================================================================
+// DEX MESSAGE TYPES
+// =============================================================================
+
+/// DEX action messages.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum DexAction {
+    AddLiquidity {
+        token: ActorId,
+        quote: Option<ActorId>,
+        token_amount: U256,
+        quote_amount: U256,
+        to: ActorId,
+    },
+    TransferLiquidity {
+        token: ActorId,
+        quote: Option<ActorId>,
+        to: ActorId,
+        amount: U256,
+    },
+}
+
+/// DEX query messages.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum DexQuery {
+    Reserves { token: ActorId, quote: Option<ActorId> },
+    LiquidityOf { token: ActorId, quote: Option<ActorId>, account: ActorId },
+}
+
+// =============================================================================
+// DEX CLIENT
+// =============================================================================
+
+/// DEX client for async liquidity operations.
+pub struct DexClient;
+
+impl DexClient {
+    /// Send an action (with optional native value) and return the raw reply.
+    async fn send_action(dex: ActorId, action: DexAction, value: u128) -> Result<Vec<u8>, ContractError> {
+        gstd::msg::send_bytes_for_reply(dex, action.encode(), value, 0)
+            .map_err(|_| ContractError::TransferFailed)?
+            .await
+            .map_err(|_| ContractError::TransferFailed)
+    }
+
+    /// Add liquidity to the `(token, quote)` pool; returns the liquidity minted to `to`.
+    pub async fn add_liquidity(
+        dex: ActorId,
+        token: ActorId,
+        quote: Option<ActorId>,
+        token_amount: U256,
+        quote_amount: U256,
+        to: ActorId,
+    ) -> Result<U256, ContractError> {
+        // Native VARA is attached to the message instead of pulled
+        let value = if quote.is_none() { quote_amount } else { 0 };
+        let response = Self::send_action(
+            dex,
+            DexAction::AddLiquidity { token, quote, token_amount, quote_amount, to },
+            value,
+        ).await?;
+
+        U256::decode(&mut response.as_slice())
+            .map_err(|_| ContractError::TransferFailed)
+    }
+
+    /// Move liquidity of the `(token, quote)` pool held by the launchpad.
+    pub async fn transfer_liquidity(
+        dex: ActorId,
+        token: ActorId,
+        quote: Option<ActorId>,
+        to: ActorId,
+        amount: U256,
+    ) -> Result<(), ContractError> {
+        Self::send_action(dex, DexAction::TransferLiquidity { token, quote, to, amount }, 0).await?;
+        Ok(())
+    }
+}
@@ -5,7 +5,7 @@
 //! - Async VFT token transfers with error handling
 //! - Safe math throughout
 //! - Comprehensive events for indexers
-//! - Rug-friendly but technically robust
+//! - Optional DEX liquidity held under a time lock, otherwise rug-friendly but technically robust
 
 #![no_std]
 
@@ -14,15 +14,29 @@ extern crate alloc;
 use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::string::String;
 use alloc::vec::Vec;
+use core::cell::{Ref, RefCell, RefMut};
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use sails_rs::prelude::*;
-use vara_contracts_shared::{Amount, BlockNumber, ContractError, Id, VestingConfig};
-
+// `U256` below is vft_client's `u128` alias; this is the real 256-bit type
+use sails_rs::U256 as WideU256;
+use vara_contracts_shared::{
+    Amount, BasisPoints, BlockNumber, ContractError, Id, Milestone, RoleId, Status, TokenType,
+    VestingConfig, VestingCurve, VoteChoice, MAX_BASIS_POINTS,
+};
+
+mod dex_client;
+pub mod merkle;
+#[allow(dead_code)]
 mod vft_client;
+#[allow(dead_code)]
 mod vft_factory;
-use vft_client::{VftClient, TokenMetadata, TokenHolder, LaunchTokenInfo, U256};
+use vft_client::{
+    launchpad_token_balance, verify_token_approval, LaunchTokenInfo, TokenHolder, TokenMetadata, VftClient, U256,
+};
 use vft_factory::VftFactory;
+use dex_client::DexClient;
+use merkle::{Hash, MerkleProof};
 
 // =============================================================================
 // STATE MACHINE
@@ -80,8 +94,12 @@ pub struct Launch {
     pub total_tokens: Amount,
     /// Tokens remaining for sale.
     pub tokens_remaining: Amount,
-    /// Price per token in native currency (VARA).
+    /// Unsold tokens already returned to the creator.
+    pub unsold_returned: Amount,
+    /// Price per token in the payment currency.
     pub price_per_token: Amount,
+    /// Currency contributions are raised in (native VARA or a VFT).
+    pub payment_token: TokenType,
     /// Minimum total raise required (soft cap).
     pub min_raise: Amount,
     /// Maximum total raise (hard cap).
@@ -92,12 +110,44 @@ pub struct Launch {
     pub max_per_wallet: Amount,
     /// Launch start time (block number).
     pub start_time: BlockNumber,
-    /// Launch end time (block number).
+    /// Launch end time (block number), including any extensions.
     pub end_time: BlockNumber,
+    /// Optional end-time extension for contributions near the close.
+    pub anti_sniping: Option<AntiSnipingConfig>,
+    /// Blocks added to `end_time` by anti-sniping so far.
+    pub anti_sniping_extended: BlockNumber,
+    /// Blocks the creator may add once if the soft cap is missed (0 = disabled).
+    pub soft_cap_extension: BlockNumber,
+    /// Whether the soft-cap extension has been used.
+    pub soft_cap_extended: bool,
+    /// Whether the launchpad schedules its own activation and finalization.
+    pub automated: bool,
     /// Optional whitelist addresses.
     pub whitelist: BTreeSet<ActorId>,
     /// Is whitelist enabled.
     pub whitelist_enabled: bool,
+    /// Merkle root of the allowlist; when set, contributions must carry a proof.
+    pub whitelist_root: Option<Hash>,
+    /// Optional sale phases, each with its own window, price, cap and eligibility.
+    pub phases: Vec<SalePhase>,
+    /// Contributions per phase and address.
+    pub phase_contributions: BTreeMap<(u32, ActorId), Amount>,
+    /// Optional descending-price auction replacing the fixed price.
+    pub auction: Option<DutchAuction>,
+    /// Uniform price every buyer settles at, once the auction has cleared.
+    pub clearing_price: Option<Amount>,
+    /// Accept contributions beyond the hard cap and allocate pro-rata on finalize.
+    pub pro_rata: bool,
+    /// Optional bonding curve replacing the fixed price.
+    pub bonding_curve: Option<BondingCurve>,
+    /// Optional share of the raise seeded into a DEX pool on success.
+    pub liquidity: Option<LiquidityConfig>,
+    /// Tokens set aside (on top of `total_tokens`) to pair with the seeded funds.
+    pub liquidity_reserve: Amount,
+    /// Liquidity added to the DEX, once seeded.
+    pub seeded_liquidity: Option<SeededLiquidity>,
+    /// Payment owed back to contributors after settlement, paid out on claim.
+    pub excess_refunds: BTreeMap<ActorId, Amount>,
     /// Contributions per address.
     pub contributions: BTreeMap<ActorId, Amount>,
     /// Tokens purchased per address.
@@ -106,6 +156,23 @@ pub struct Launch {
     pub claimed: BTreeMap<ActorId, Amount>,
     /// Optional vesting configuration.
     pub vesting_config: Option<VestingConfig>,
+    /// Whether holders may transfer their purchased positions.
+    pub positions_transferable: bool,
+    /// Optional creator/team allocation minted on top of the sale.
+    pub team_allocation: Option<TeamAllocation>,
+    /// Team tokens allocated per beneficiary.
+    pub team_tokens: BTreeMap<ActorId, Amount>,
+    /// Team tokens claimed per beneficiary.
+    pub team_claimed: BTreeMap<ActorId, Amount>,
+    /// Revoked team schedules per beneficiary.
+    pub team_revocations: BTreeMap<ActorId, TeamRevocation>,
+    /// Optional milestones gating the release of raised funds.
+    /// Each `amount` is the milestone's tranche in basis points of the raise.
+    pub milestones: Vec<Milestone>,
+    /// Contributors who approved each milestone, by milestone index.
+    pub milestone_approvals: BTreeMap<u32, BTreeSet<ActorId>>,
+    /// Optional contributor governance settings.
+    pub governance: Option<GovernanceConfig>,
     /// Current status.
     pub status: LaunchStatus,
     /// Block when launch was created.
@@ -114,10 +181,16 @@ pub struct Launch {
     pub tokens_deposited: bool,
     /// Whether creator has withdrawn funds.
     pub funds_withdrawn: bool,
+    /// Raised funds released to the creator so far (before fees).
+    pub funds_released: Amount,
     /// Whether refunds have been processed.
     pub refunds_processed: bool,
     /// Contributors list for batch operations.
     pub contributors: Vec<ActorId>,
+    /// Next contributor index for `distribute_batch`.
+    pub distribution_cursor: u32,
+    /// Next contributor index for `refund_batch`.
+    pub refund_cursor: u32,
     /// Deadline for finalization (end_time + grace period). After this, admin can force refunds.
     pub finalization_deadline: BlockNumber,
 }
@@ -141,11 +214,163 @@ impl Launch {
         current_block >= self.start_time && current_block <= self.end_time
     }
 
+    /// Move `end_time` back by `blocks`; returns the previous end time.
+    fn extend_end_time(&mut self, blocks: BlockNumber) -> BlockNumber {
+        let old_end_time = self.end_time;
+        self.end_time = old_end_time.saturating_add(blocks);
+        self.finalization_deadline = self.end_time.saturating_add(FINALIZATION_GRACE_PERIOD);
+        old_end_time
+    }
+
+    /// Extend the sale for a contribution accepted at `current_block` inside
+    /// the anti-sniping window, within the configured maximum.
+    ///
+    /// Returns the previous end time if the sale was extended.
+    fn extend_for_late_contribution(&mut self, current_block: BlockNumber) -> Option<BlockNumber> {
+        let config = self.anti_sniping.as_ref()?;
+        if self.end_time.saturating_sub(current_block) >= config.window {
+            return None;
+        }
+        let blocks = config.extension.min(config.max_extension.saturating_sub(self.anti_sniping_extended));
+        if blocks == 0 {
+            return None;
+        }
+        self.anti_sniping_extended = self.anti_sniping_extended.saturating_add(blocks);
+        Some(self.extend_end_time(blocks))
+    }
+
+    /// Whether the creator can still extend an undersubscribed sale: the soft
+    /// cap is missed, the extension is unused and at most `soft_cap_extension`
+    /// blocks have passed since `end_time`.
+    pub fn can_extend_for_soft_cap(&self, current_block: BlockNumber) -> bool {
+        self.status == LaunchStatus::Active
+            && self.soft_cap_extension > 0
+            && !self.soft_cap_extended
+            && self.total_raised < self.min_raise
+            && current_block <= self.end_time.saturating_add(self.soft_cap_extension)
+    }
+
+    /// First block at which `finalize` closes a sale that has not sold out,
+    /// after any pending soft-cap extension window.
+    pub fn finalization_block(&self) -> BlockNumber {
+        let mut end = self.end_time;
+        if self.soft_cap_extension > 0 && !self.soft_cap_extended && self.total_raised < self.min_raise {
+            end = end.saturating_add(self.soft_cap_extension);
+        }
+        end.saturating_add(1)
+    }
+
     /// Check if address is allowed to participate.
     pub fn can_participate(&self, address: &ActorId) -> bool {
         !self.whitelist_enabled || self.whitelist.contains(address)
     }
 
+    /// Check that `address` may participate and return its wallet cap.
+    ///
+    /// Merkle allowlists need a proof; a non-zero allocation in the leaf
+    /// replaces `max_per_wallet` for that address.
+    pub fn wallet_cap(&self, address: &ActorId, proof: Option<&MerkleProof>) -> Result<Amount, ContractError> {
+        let Some(root) = self.whitelist_root else {
+            if !self.can_participate(address) {
+                return Err(ContractError::invalid_state("Not whitelisted"));
+            }
+            return Ok(self.max_per_wallet);
+        };
+
+        let proof = proof.ok_or(ContractError::invalid_state("Whitelist proof required"))?;
+        if !merkle::verify(&root, merkle::leaf(address, proof.allocation), &proof.proof) {
+            return Err(ContractError::invalid_state("Not whitelisted"));
+        }
+
+        Ok(if proof.allocation > 0 { proof.allocation } else { self.max_per_wallet })
+    }
+
+    /// Index and definition of the phase running at `current_block`.
+    pub fn current_phase(&self, current_block: BlockNumber) -> Option<(u32, &SalePhase)> {
+        self.phases
+            .iter()
+            .enumerate()
+            .find(|(_, phase)| phase.is_active(current_block))
+            .map(|(index, phase)| (index as u32, phase))
+    }
+
+    /// Price per token at `current_block`, following the active phase or auction.
+    pub fn price_at(&self, current_block: BlockNumber) -> Amount {
+        if let Some(price) = self.clearing_price {
+            return price;
+        }
+        if let Some((_, phase)) = self.current_phase(current_block) {
+            return phase.price_per_token;
+        }
+        if let Some(ref curve) = self.bonding_curve {
+            return curve.spot_price(self.tokens_sold());
+        }
+        match self.auction {
+            Some(ref auction) => auction.price_at(self.start_time, self.end_time, current_block),
+            None => self.price_per_token,
+        }
+    }
+
+    /// Settle every buyer at `clearing_price`, moving what they overpaid to
+    /// `excess_refunds`.
+    fn settle_auction(&mut self, clearing_price: Amount) {
+        let mut total_raised: Amount = 0;
+        for (contributor, contribution) in self.contributions.iter_mut() {
+            let tokens = self.tokens_purchased.get(contributor).copied().unwrap_or(0);
+            let cost = tokens.saturating_mul(clearing_price).min(*contribution);
+            let excess = contribution.saturating_sub(cost);
+            if excess > 0 {
+                *self.excess_refunds.entry(*contributor).or_insert(0) += excess;
+            }
+            *contribution = cost;
+            total_raised = total_raised.saturating_add(cost);
+        }
+
+        self.total_raised = total_raised;
+        self.price_per_token = clearing_price;
+        self.clearing_price = Some(clearing_price);
+    }
+
+    /// Scale every allocation down so the raise fits `max_raise` and the
+    /// tokens fit `total_tokens`, moving the unused payment to `excess_refunds`.
+    fn settle_pro_rata(&mut self) {
+        let requested: Amount = self.tokens_purchased.values().sum();
+
+        // Pick whichever limit is tighter as the scaling fraction
+        // (compared in 256 bits, as either product can overflow `Amount`)
+        let (num, den) = if wide_mul(self.max_raise, requested)
+            <= wide_mul(self.total_tokens, self.total_raised)
+        {
+            (self.max_raise, self.total_raised)
+        } else {
+            (self.total_tokens, requested)
+        };
+        let oversubscribed = num < den;
+
+        let mut total_raised: Amount = 0;
+        let mut allocated: Amount = 0;
+        for (contributor, contribution) in self.contributions.iter_mut() {
+            let Some(tokens) = self.tokens_purchased.get_mut(contributor) else {
+                continue;
+            };
+            if oversubscribed {
+                let allocation = mul_div(*tokens, num, den);
+                let cost = mul_div(*contribution, allocation, *tokens);
+                let excess = contribution.saturating_sub(cost);
+                if excess > 0 {
+                    *self.excess_refunds.entry(*contributor).or_insert(0) += excess;
+                }
+                *tokens = allocation;
+                *contribution = cost;
+            }
+            total_raised = total_raised.saturating_add(*contribution);
+            allocated = allocated.saturating_add(*tokens);
+        }
+
+        self.total_raised = total_raised;
+        self.tokens_remaining = self.total_tokens.saturating_sub(allocated);
+    }
+
     /// Get remaining allocation for a wallet.
     pub fn remaining_allocation(&self, address: &ActorId) -> Amount {
         let contributed = self.contributions.get(address).copied().unwrap_or(0);
@@ -157,1155 +382,4793 @@ impl Launch {
         self.total_raised >= self.min_raise
     }
 
-    /// Check if hard cap reached.
+    /// Check if hard cap reached (or a bonding curve graduated).
+    ///
+    /// Pro-rata sales are never closed early by demand.
     pub fn is_fully_subscribed(&self) -> bool {
-        self.tokens_remaining == 0 || self.total_raised >= self.max_raise
+        !self.pro_rata
+            && (self.tokens_remaining == 0 || self.total_raised >= self.max_raise || self.is_graduated())
     }
-}
 
-/// Input for creating a new launch.
-#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
-#[codec(crate = sails_rs::scale_codec)]
-#[scale_info(crate = sails_rs::scale_info)]
-pub struct CreateLaunchInput {
-    // Token creation parameters
-    pub token_name: String,
-    pub token_symbol: String,
-    
-    // Launch parameters
-    pub title: String,
-    pub description: String,
-    pub total_tokens: Amount,
-    pub price_per_token: Amount,
-    pub min_raise: Amount,
-    pub max_raise: Amount,
-    pub max_per_wallet: Amount,
-    pub start_time: BlockNumber,
-    pub end_time: BlockNumber,
-    pub whitelist_enabled: bool,
-    pub vesting_config: Option<VestingConfig>,
-}
+    /// Check if a bonding-curve reserve reached its graduation target.
+    pub fn is_graduated(&self) -> bool {
+        self.bonding_curve
+            .as_ref()
+            .is_some_and(|curve| self.total_raised >= curve.target_reserve)
+    }
 
-// =============================================================================
-// STORAGE
-// =============================================================================
+    /// Tokens sold so far.
+    pub fn tokens_sold(&self) -> Amount {
+        self.total_tokens.saturating_sub(self.tokens_remaining)
+    }
 
-/// Storage for the Launchpad contract.
-#[derive(Default)]
-pub struct LaunchpadStorage {
-    launches: BTreeMap<Id, Launch>,
-    next_launch_id: Id,
-    owner: ActorId,
-    /// Platform fee in basis points (100 = 1%).
-    fee_basis_points: u16,
-    /// Total accumulated fees.
-    accumulated_fees: Amount,
-    /// Total fees withdrawn.
-    fees_withdrawn: Amount,
-    /// Paused state.
-    paused: bool,
-    /// Code ID of the VFT token contract for deployment.
-    vft_code_id: CodeId,
-    /// Reentrancy guard to prevent recursive calls.
-    reentrancy_guard: bool,
-    /// Fee recipient address (defaults to owner).
-    fee_recipient: ActorId,
-    /// Gas allocated for program creation.
-    gas_for_program: u64,
-    /// Gas allocated for reply handling.
-    gas_for_reply: u64,
-}
+    /// Check if the launch raised successfully and is paying out.
+    pub fn is_successful(&self) -> bool {
+        matches!(self.status, LaunchStatus::DistributionPending | LaunchStatus::Succeeded)
+    }
 
-fn storage_mut() -> &'static mut LaunchpadStorage {
-    unsafe {
-        static mut STORAGE: Option<LaunchpadStorage> = None;
-        STORAGE.get_or_insert_with(LaunchpadStorage::default)
+    /// Index of the next milestone awaiting completion.
+    pub fn next_milestone(&self) -> Option<u32> {
+        self.milestones.iter().position(|m| !m.completed).map(|i| i as u32)
     }
-}
 
-fn storage() -> &'static LaunchpadStorage {
-    unsafe {
-        static mut STORAGE: Option<LaunchpadStorage> = None;
-        STORAGE.get_or_insert_with(LaunchpadStorage::default)
+    /// Share of the raise set aside for DEX liquidity.
+    pub fn liquidity_funds(&self) -> Amount {
+        self.liquidity
+            .as_ref()
+            .map(|config| {
+                self.total_raised
+                    .saturating_mul(config.raise_bps as Amount)
+                    .checked_div(MAX_BASIS_POINTS as Amount)
+                    .unwrap_or(0)
+            })
+            .unwrap_or(0)
     }
-}
 
-fn init_storage(owner: ActorId, fee_basis_points: u16) {
-    let s = storage_mut();
-    s.owner = owner;
-    s.fee_basis_points = fee_basis_points;
-    // Default to Gear's standard VFT code ID
-    // This can be updated via set_vft_code_id() after deployment
-    s.vft_code_id = CodeId::default();
-    // Fee recipient defaults to owner
-    s.fee_recipient = owner;
-    // Default gas values for program creation
-    s.gas_for_program = 10_000_000_000;  // 10 billion
-    s.gas_for_reply = 5_000_000_000;     // 5 billion
-}
+    /// Share of the raise that goes to the creator.
+    pub fn creator_funds(&self) -> Amount {
+        self.total_raised.saturating_sub(self.liquidity_funds())
+    }
 
-// =============================================================================
-// SECURITY HELPERS
-// =============================================================================
+    /// Tokens the launchpad must hold: the sale, the liquidity reserve and
+    /// the team allocation.
+    pub fn token_supply(&self) -> Amount {
+        let team = self.team_allocation.as_ref().map_or(0, |team| team.total);
+        self.total_tokens
+            .saturating_add(self.liquidity_reserve)
+            .saturating_add(team)
+    }
 
-/// Reentrancy guard to prevent recursive calls.
-struct ReentrancyGuard;
+    /// Tokens the launchpad still holds for this launch: the minted supply
+    /// less what was claimed, paired into the pool, paid to or revoked from
+    /// the team, or returned unsold.
+    pub fn tokens_held(&self) -> Amount {
+        let claimed: Amount = self.claimed.values().sum();
+        let team_claimed: Amount = self.team_claimed.values().sum();
+        let team_revoked: Amount = self.team_revocations.values().map(|revocation| revocation.revoked).sum();
+        let seeded = self.seeded_liquidity.as_ref().map_or(0, |seeded| seeded.tokens);
+        self.token_supply()
+            .saturating_sub(claimed)
+            .saturating_sub(team_claimed)
+            .saturating_sub(team_revoked)
+            .saturating_sub(seeded)
+            .saturating_sub(self.unsold_returned)
+    }
 
-impl ReentrancyGuard {
-    /// Start a guarded operation.
-    fn start() -> Result<Self, ContractError> {
-        let s = storage_mut();
-        if s.reentrancy_guard {
-            return Err(ContractError::invalid_state("Reentrant call"));
+    /// Purchased tokens `user` can claim at `block` (accounting for vesting).
+    pub fn claimable_tokens(&self, user: &ActorId, block: BlockNumber) -> Amount {
+        let total_purchased = self.tokens_purchased.get(user).copied().unwrap_or(0);
+        let already_claimed = self.claimed.get(user).copied().unwrap_or(0);
+        match self.vesting_config {
+            Some(ref vesting) => calculate_vested_tokens(total_purchased, vesting, block).saturating_sub(already_claimed),
+            None => total_purchased.saturating_sub(already_claimed),
         }
-        s.reentrancy_guard = true;
-        Ok(ReentrancyGuard)
     }
-}
 
-impl Drop for ReentrancyGuard {
-    fn drop(&mut self) {
-        let s = storage_mut();
-        s.reentrancy_guard = false;
+    /// Team tokens `beneficiary` can claim at `block`.
+    pub fn claimable_team_tokens(&self, beneficiary: &ActorId, block: BlockNumber) -> Amount {
+        let (Some(team), Some(&allocated)) = (&self.team_allocation, self.team_tokens.get(beneficiary)) else {
+            return 0;
+        };
+        let claimed = self.team_claimed.get(beneficiary).copied().unwrap_or(0);
+        // A revoked schedule stops vesting at the revocation block
+        let block = self.team_revocations
+            .get(beneficiary)
+            .map_or(block, |revocation| block.min(revocation.at_block));
+        calculate_vested_tokens(allocated, &team.vesting, block).saturating_sub(claimed)
     }
-}
 
-// =============================================================================
-// HELPERS
-// =============================================================================
+    /// Liquidity reserve not paired into the pool, once seeding is done or released.
+    pub fn unused_liquidity_reserve(&self) -> Amount {
+        match self.seeded_liquidity {
+            Some(ref seeded) => self.liquidity_reserve.saturating_sub(seeded.tokens),
+            None if self.liquidity.is_none() => self.liquidity_reserve,
+            None => 0,
+        }
+    }
+
+    /// Raised funds unlocked for the creator (before fees).
+    ///
+    /// Without milestones the whole creator share is unlocked.
+    pub fn unlocked_funds(&self) -> Amount {
+        let creator_funds = self.creator_funds();
+        if self.milestones.is_empty() {
+            return creator_funds;
+        }
+
+        let completed_bps: Amount = self.milestones
+            .iter()
+            .filter(|m| m.completed)
+            .map(|m| m.amount)
+            .sum();
+
+        if completed_bps >= MAX_BASIS_POINTS as Amount {
+            return creator_funds;
+        }
 
-/// Transfer native tokens to recipient.
-fn transfer_native(to: ActorId, amount: Amount) -> Result<(), ContractError> {
-    if amount == 0 {
-        return Ok(());
+        creator_funds
+            .saturating_mul(completed_bps)
+            .checked_div(MAX_BASIS_POINTS as Amount)
+            .unwrap_or(0)
     }
-    gstd::msg::send_bytes(to, [], amount as u128)
-        .map_err(|_| ContractError::TransferFailed)?;
-    Ok(())
-}
 
-/// Calculate vested tokens with proper rounding.
-/// Uses SCALE factor to prevent precision loss.
-const VESTING_SCALE: u128 = 1_000_000_000_000; // 10^12
+    /// Unlocked funds not yet released to the creator.
+    pub fn releasable_funds(&self) -> Amount {
+        self.unlocked_funds().saturating_sub(self.funds_released)
+    }
 
-fn calculate_vested_tokens(
-    total_tokens: Amount,
-    vesting: &VestingConfig,
-    current_block: BlockNumber,
-) -> Amount {
-    // Before cliff - nothing vested
-    let cliff_end = vesting.cliff_end();
-    if current_block < cliff_end {
-        return 0;
+    /// Raised funds still held: neither released to the creator nor paired
+    /// into the pool.
+    pub fn retained_funds(&self) -> Amount {
+        let seeded = self.seeded_liquidity.as_ref().map_or(0, |seeded| seeded.funds);
+        self.total_raised
+            .saturating_sub(self.funds_released)
+            .saturating_sub(seeded)
     }
 
-    // After vesting end - everything vested
-    let vesting_end = vesting.vesting_end();
-    if current_block >= vesting_end {
-        return total_tokens;
+    /// Refund owed for `contribution`, pro-rata to the funds still held.
+    pub fn refund_for(&self, contribution: Amount) -> Amount {
+        let retained = self.retained_funds();
+        if retained == self.total_raised || self.total_raised == 0 {
+            return contribution;
+        }
+
+        contribution
+            .saturating_mul(retained)
+            .checked_div(self.total_raised)
+            .unwrap_or(0)
     }
 
-    // During vesting - linear interpolation with scaled math
-    let vesting_duration = vesting.vesting_duration as u128;
-    if vesting_duration == 0 {
-        return total_tokens;
+    /// Check that milestone `index` is the next one that can be completed.
+    fn ensure_next_milestone(&self, index: u32) -> Result<(), ContractError> {
+        if !self.is_successful() {
+            return Err(ContractError::invalid_state("Launch not successful"));
+        }
+
+        match self.next_milestone() {
+            Some(next) if next == index => Ok(()),
+            Some(_) => Err(ContractError::invalid_input("Milestones must be completed in order")),
+            None if (index as usize) < self.milestones.len() => Err(ContractError::AlreadyProcessed),
+            None => Err(ContractError::NotFound),
+        }
+    }
+
+    /// Mark milestone `index` completed and return the tranche it unlocked.
+    fn complete_milestone(&mut self, index: u32) -> Amount {
+        let unlocked_before = self.unlocked_funds();
+        if let Some(milestone) = self.milestones.get_mut(index as usize) {
+            milestone.completed = true;
+        }
+        self.unlocked_funds().saturating_sub(unlocked_before)
+    }
+
+    /// Check that `action` can currently be applied to this launch.
+    fn ensure_proposal_action(&self, action: &ProposalAction) -> Result<(), ContractError> {
+        match *action {
+            ProposalAction::CancelLaunch => {
+                if self.status != LaunchStatus::Active {
+                    return Err(ContractError::invalid_state("Launch is not active"));
+                }
+            }
+            ProposalAction::ExtendEndTime(new_end_time) => {
+                if self.status != LaunchStatus::Active {
+                    return Err(ContractError::invalid_state("Launch is not active"));
+                }
+                if new_end_time <= self.end_time {
+                    return Err(ContractError::invalid_input("New end time must be later"));
+                }
+                // Auction decay and phase windows are fixed to the original schedule
+                if self.auction.is_some() || !self.phases.is_empty() {
+                    return Err(ContractError::invalid_state("End time cannot be extended for auctions or phases"));
+                }
+                if let Some(ref vesting) = self.vesting_config {
+                    if vesting.vesting_end() < new_end_time {
+                        return Err(ContractError::invalid_input("Vesting must end after launch ends"));
+                    }
+                }
+            }
+            ProposalAction::ApproveMilestone(index) => self.ensure_next_milestone(index)?,
+        }
+        Ok(())
+    }
+
+    /// Combined contribution of everyone who approved milestone `index`.
+    fn milestone_approval_weight(&self, index: u32) -> Amount {
+        self.milestone_approvals
+            .get(&index)
+            .map(|approvers| {
+                approvers
+                    .iter()
+                    .map(|a| self.contributions.get(a).copied().unwrap_or(0))
+                    .sum()
+            })
+            .unwrap_or(0)
+    }
+
+    /// Validate a contribution of `offered` and quote what it buys.
+    ///
+    /// Anything offered above the quoted amount is excess that must go back
+    /// to the contributor.
+    pub fn quote_contribution(
+        &self,
+        contributor: &ActorId,
+        offered: Amount,
+        current_block: BlockNumber,
+        proof: Option<&MerkleProof>,
+    ) -> Result<ContributionQuote, ContractError> {
+        // Status check
+        if self.status != LaunchStatus::Active {
+            return Err(ContractError::invalid_state("Launch is not active"));
+        }
+
+        // Time window check
+        if !self.is_in_time_window(current_block) {
+            return Err(ContractError::invalid_state("Outside contribution window"));
+        }
+
+        // Phase and whitelist checks
+        let phase = self.current_phase(current_block);
+        if !self.phases.is_empty() && phase.is_none() {
+            return Err(ContractError::invalid_state("No sale phase is active"));
+        }
+
+        let wallet_cap = self.wallet_cap(contributor, proof)?;
+        let price = self.price_at(current_block);
+        let phase_remaining = match phase {
+            Some((index, phase)) => {
+                let phase_cap = phase.wallet_cap(contributor, proof)?;
+                let phase_contributed = self.phase_contributions
+                    .get(&(index, *contributor))
+                    .copied()
+                    .unwrap_or(0);
+                phase_cap.saturating_sub(phase_contributed)
+            }
+            None => Amount::MAX,
+        };
+
+        // Check if fully subscribed
+        if self.is_fully_subscribed() {
+            return Err(ContractError::invalid_state("Sale is fully subscribed"));
+        }
+
+        // Calculate maximum contribution
+        let contributed = self.contributions.get(contributor).copied().unwrap_or(0);
+        let wallet_remaining = wallet_cap.saturating_sub(contributed);
+        let raise_remaining = if self.pro_rata {
+            Amount::MAX
+        } else {
+            self.max_raise.saturating_sub(self.total_raised)
+        };
+        let max_contribution = wallet_remaining.min(raise_remaining).min(phase_remaining);
+
+        if max_contribution == 0 {
+            return Err(ContractError::invalid_state("No allocation remaining"));
+        }
+
+        // A bonding curve prices every token along the curve
+        if let Some(ref curve) = self.bonding_curve {
+            let (tokens, cost) = curve.buy(self.tokens_sold(), offered.min(max_contribution), self.tokens_remaining);
+            if tokens == 0 {
+                return Err(ContractError::invalid_input("Contribution too small for any tokens"));
+            }
+            return Ok(ContributionQuote {
+                amount: cost,
+                tokens,
+                price: cost / tokens,
+                phase: None,
+            });
+        }
+
+        // Calculate tokens to purchase
+        let tokens_to_purchase = offered.min(max_contribution).checked_div(price).unwrap_or(0);
+
+        // Handle edge case: contribution too small for even 1 token
+        if tokens_to_purchase == 0 {
+            return Err(ContractError::invalid_input("Contribution too small for any tokens"));
+        }
+
+        // Check token availability (pro-rata demand is scaled down on finalize)
+        let tokens_to_purchase = if self.pro_rata {
+            tokens_to_purchase
+        } else {
+            tokens_to_purchase.min(self.tokens_remaining)
+        };
+        Ok(ContributionQuote {
+            amount: tokens_to_purchase.saturating_mul(price),
+            tokens: tokens_to_purchase,
+            price,
+            phase: phase.map(|(index, _)| index),
+        })
+    }
+
+    /// Record an accepted contribution.
+    fn record_contribution(&mut self, contributor: ActorId, quote: &ContributionQuote) {
+        let ContributionQuote { amount, tokens, price, phase } = *quote;
+        if let Some(index) = phase {
+            *self.phase_contributions.entry((index, contributor)).or_insert(0) += amount;
+        }
+        *self.contributions.entry(contributor).or_insert(0) += amount;
+        *self.tokens_purchased.entry(contributor).or_insert(0) += tokens;
+        self.total_raised = self.total_raised.saturating_add(amount);
+        self.tokens_remaining = self.tokens_remaining.saturating_sub(tokens);
+
+        // An auction clears at the price of the order that sold it out
+        if self.auction.is_some() && self.is_fully_subscribed() {
+            self.clearing_price = Some(price);
+        }
+
+        // Track contributor
+        if !self.contributors.contains(&contributor) {
+            self.contributors.push(contributor);
+        }
+    }
+
+    /// Record `tokens` sold back to the curve for `proceeds`.
+    ///
+    /// Returns how much of the seller's contribution was released.
+    fn record_sale(&mut self, seller: ActorId, tokens: Amount, proceeds: Amount) -> Amount {
+        let held = self.tokens_purchased.get(&seller).copied().unwrap_or(0);
+        let contributed = self.contributions.get(&seller).copied().unwrap_or(0);
+        let released = if held == tokens { contributed } else { contributed.min(proceeds) };
+
+        if held == tokens {
+            self.contributions.remove(&seller);
+            self.tokens_purchased.remove(&seller);
+            self.contributors.retain(|c| *c != seller);
+        } else {
+            self.contributions.insert(seller, contributed - released);
+            self.tokens_purchased.insert(seller, held - tokens);
+        }
+        self.total_raised = self.total_raised.saturating_sub(proceeds);
+        self.tokens_remaining = self.tokens_remaining.saturating_add(tokens);
+        released
+    }
+
+    /// Undo a sale recorded by `record_sale` whose payout failed.
+    fn revert_sale(&mut self, seller: ActorId, tokens: Amount, proceeds: Amount, released: Amount) {
+        if !self.contributors.contains(&seller) {
+            self.contributors.push(seller);
+        }
+        *self.contributions.entry(seller).or_insert(0) += released;
+        *self.tokens_purchased.entry(seller).or_insert(0) += tokens;
+        self.total_raised = self.total_raised.saturating_add(proceeds);
+        self.tokens_remaining = self.tokens_remaining.saturating_sub(tokens);
     }
 
-    let elapsed = (current_block.saturating_sub(vesting.start_block)) as u128;
+    /// Move `amount` of `from`'s unclaimed tokens to `to`.
+    ///
+    /// Purchased and claimed amounts move in the position's claimed ratio, so
+    /// both holders keep the same vesting progress.
+    fn transfer_position(&mut self, from: ActorId, to: ActorId, amount: Amount) -> Result<(), ContractError> {
+        let held = self.tokens_purchased.get(&from).copied().unwrap_or(0);
+        let claimed = self.claimed.get(&from).copied().unwrap_or(0);
+        let unclaimed = held.saturating_sub(claimed);
+        if amount > unclaimed {
+            return Err(ContractError::AmountExceedsMax);
+        }
+
+        let purchased_moved = amount
+            .saturating_mul(held)
+            .checked_div(unclaimed)
+            .unwrap_or(0);
+        let claimed_moved = purchased_moved.saturating_sub(amount);
+
+        if amount == unclaimed {
+            self.tokens_purchased.remove(&from);
+            self.claimed.remove(&from);
+        } else {
+            self.tokens_purchased.insert(from, held - purchased_moved);
+            self.claimed.insert(from, claimed - claimed_moved);
+        }
+        *self.tokens_purchased.entry(to).or_insert(0) += purchased_moved;
+        if claimed_moved > 0 {
+            *self.claimed.entry(to).or_insert(0) += claimed_moved;
+        }
 
-    // Scale up, divide, scale down to minimize rounding errors
-    let scaled_tokens = total_tokens.saturating_mul(VESTING_SCALE);
-    let scaled_elapsed = elapsed.saturating_mul(VESTING_SCALE);
+        // Recipients are paid by batch distribution too
+        if !self.contributors.contains(&to) {
+            self.contributors.push(to);
+        }
+        Ok(())
+    }
 
-    scaled_tokens
-        .saturating_mul(scaled_elapsed)
-        .checked_div(vesting_duration.saturating_mul(VESTING_SCALE).saturating_mul(VESTING_SCALE))
-        .unwrap_or(0)
+    /// Undo a contribution recorded by `record_contribution` whose payment failed.
+    fn revert_contribution(&mut self, contributor: ActorId, quote: &ContributionQuote) {
+        let ContributionQuote { amount, tokens, phase, .. } = *quote;
+        if let Some(index) = phase {
+            if let Some(contributed) = self.phase_contributions.get_mut(&(index, contributor)) {
+                *contributed = contributed.saturating_sub(amount);
+            }
+        }
+        let contributed = self.contributions.get(&contributor).copied().unwrap_or(0);
+        let remaining = contributed.saturating_sub(amount);
+        if remaining == 0 {
+            self.contributions.remove(&contributor);
+            self.tokens_purchased.remove(&contributor);
+            self.contributors.retain(|c| *c != contributor);
+        } else {
+            self.contributions.insert(contributor, remaining);
+            if let Some(purchased) = self.tokens_purchased.get_mut(&contributor) {
+                *purchased = purchased.saturating_sub(tokens);
+            }
+        }
+        self.total_raised = self.total_raised.saturating_sub(amount);
+        self.tokens_remaining = self.tokens_remaining.saturating_add(tokens);
+        if !self.is_fully_subscribed() {
+            self.clearing_price = None;
+        }
+    }
 }
 
-// =============================================================================
-// EVENTS
-// =============================================================================
+/// Accepted part of a contribution, as computed by `Launch::quote_contribution`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContributionQuote {
+    /// Amount of the payment currency accepted.
+    pub amount: Amount,
+    /// Tokens bought with it.
+    pub tokens: Amount,
+    /// Price per token the contribution was quoted at.
+    pub price: Amount,
+    /// Sale phase it counts towards.
+    pub phase: Option<u32>,
+}
 
-/// Events emitted by the Launchpad contract.
-#[derive(Debug, Clone, Encode, TypeInfo)]
+/// Who may contribute during a sale phase.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
-pub enum LaunchpadEvent {
-    /// New launch created.
-    LaunchCreated {
-        launch_id: Id,
-        creator: ActorId,
-        title: String,
-        token_address: ActorId,
-        total_tokens: Amount,
-        price_per_token: Amount,
-        min_raise: Amount,
-        max_raise: Amount,
-        start_time: BlockNumber,
-        end_time: BlockNumber,
-    },
-    /// Launch started and accepting contributions.
-    LaunchStarted {
-        launch_id: Id,
-    },
-    /// Sale ended (time expired or fully subscribed).
-    SaleEnded {
-        launch_id: Id,
-        total_raised: Amount,
-        total_contributors: u32,
-        reason: String,
-    },
-    /// Sale fully subscribed before end time.
-    SaleFullySubscribed {
-        launch_id: Id,
-        total_raised: Amount,
-    },
-    /// Launch succeeded (min raise met).
-    LaunchSucceeded {
-        launch_id: Id,
-        total_raised: Amount,
-    },
-    /// Launch failed (min raise not met).
-    LaunchFailed {
-        launch_id: Id,
-        total_raised: Amount,
-        min_raise: Amount,
-    },
-    /// Launch cancelled by creator.
-    LaunchCancelled {
-        launch_id: Id,
-        by: ActorId,
-    },
-    /// Distribution phase started.
-    DistributionPending {
-        launch_id: Id,
-    },
-    /// Refunds available.
-    RefundsAvailable {
-        launch_id: Id,
-        total_to_refund: Amount,
-        num_contributors: u32,
-    },
-    /// User contributed to launch.
-    Contributed {
-        launch_id: Id,
-        contributor: ActorId,
-        amount: Amount,
-        tokens_purchased: Amount,
-        refunded: Amount,
-    },
-    /// Tokens claimed by contributor.
-    TokensClaimed {
-        launch_id: Id,
-        user: ActorId,
-        amount: Amount,
-    },
-    /// Token transfer failed (for retry).
-    TokenTransferFailed {
-        launch_id: Id,
-        user: ActorId,
-        amount: Amount,
-        reason: String,
-    },
-    /// Refund claimed by contributor.
-    RefundClaimed {
-        launch_id: Id,
-        user: ActorId,
-        amount: Amount,
-    },
-    /// Creator withdrew raised funds.
-    FundsWithdrawn {
-        launch_id: Id,
-        creator: ActorId,
-        amount: Amount,
-        fee: Amount,
-    },
-    /// Platform fees withdrawn by owner.
-    FeesWithdrawn {
-        owner: ActorId,
-        amount: Amount,
-        total_accumulated: Amount,
-    },
-    /// Whitelist updated.
-    WhitelistUpdated {
-        launch_id: Id,
-        addresses_added: u32,
-    },
-    /// Tokens deposited by creator.
-    TokensDeposited {
-        launch_id: Id,
-        amount: Amount,
-    },
-    /// Launch finalized (all operations complete).
-    LaunchFinalized {
-        launch_id: Id,
-    },
-    /// Contract paused.
-    Paused {
-        by: ActorId,
-    },
-    /// Contract resumed.
-    Resumed {
-        by: ActorId,
-    },
-    /// Fee recipient updated.
-    FeeRecipientUpdated {
-        old: ActorId,
-        new: ActorId,
-    },
-    /// Admin forced refund for stuck contribution.
-    AdminForceRefund {
-        launch_id: Id,
-        user: ActorId,
-        amount: Amount,
-    },
-    /// Rescued tokens accidentally sent to contract.
-    TokensRescued {
-        token_address: ActorId,
-        amount: U256,
-        to: ActorId,
-    },
-    /// New token deployed via the factory.
-    TokenDeployed {
-        launch_id: Id,
-        token_address: ActorId,
-        name: String,
-        symbol: String,
-        total_supply: Amount,
-    },
-    /// Gas configuration updated.
-    GasConfigUpdated {
-        gas_for_program: u64,
-        gas_for_reply: u64,
-    },
+pub enum PhaseEligibility {
+    /// Anyone.
+    Public,
+    /// Only the listed addresses.
+    Addresses(BTreeSet<ActorId>),
+    /// Holders of a proof against this Merkle root (see [`merkle`]).
+    Merkle(Hash),
+}
+
+/// One round of a tiered sale.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct SalePhase {
+    pub name: String,
+    /// Phase start (block number, inclusive).
+    pub start_time: BlockNumber,
+    /// Phase end (block number, inclusive).
+    pub end_time: BlockNumber,
+    /// Price per token during this phase.
+    pub price_per_token: Amount,
+    /// Maximum contribution per wallet within this phase.
+    pub max_per_wallet: Amount,
+    pub eligibility: PhaseEligibility,
+}
+
+impl SalePhase {
+    /// Check if the phase is running at `current_block`.
+    pub fn is_active(&self, current_block: BlockNumber) -> bool {
+        current_block >= self.start_time && current_block <= self.end_time
+    }
+
+    /// Check that `address` is eligible and return its cap for this phase.
+    ///
+    /// A non-zero allocation in a Merkle leaf replaces the phase cap.
+    pub fn wallet_cap(&self, address: &ActorId, proof: Option<&MerkleProof>) -> Result<Amount, ContractError> {
+        match self.eligibility {
+            PhaseEligibility::Public => Ok(self.max_per_wallet),
+            PhaseEligibility::Addresses(ref addresses) => {
+                if !addresses.contains(address) {
+                    return Err(ContractError::invalid_state("Not eligible for this phase"));
+                }
+                Ok(self.max_per_wallet)
+            }
+            PhaseEligibility::Merkle(root) => {
+                let proof = proof.ok_or(ContractError::invalid_state("Whitelist proof required"))?;
+                if !merkle::verify(&root, merkle::leaf(address, proof.allocation), &proof.proof) {
+                    return Err(ContractError::invalid_state("Not eligible for this phase"));
+                }
+                Ok(if proof.allocation > 0 { proof.allocation } else { self.max_per_wallet })
+            }
+        }
+    }
+}
+
+/// How a Dutch auction's price falls from `start_price` to `floor_price`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum DecayCurve {
+    /// Straight line over the sale window.
+    Linear,
+    /// Linear, but only updated every `interval` blocks.
+    Stepped { interval: BlockNumber },
+    /// The distance to the floor halves every `half_life` blocks.
+    Exponential { half_life: BlockNumber },
+}
+
+/// Descending-price auction over the launch window.
+///
+/// Buyers pay the live price when they contribute. Once the sale ends (or
+/// sells out) everyone settles at the final clearing price and the difference
+/// is refunded when they claim.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct DutchAuction {
+    pub start_price: Amount,
+    pub floor_price: Amount,
+    pub curve: DecayCurve,
+}
+
+impl DutchAuction {
+    /// Auction price at `current_block` for a sale running `start_time..=end_time`.
+    pub fn price_at(&self, start_time: BlockNumber, end_time: BlockNumber, current_block: BlockNumber) -> Amount {
+        if current_block <= start_time {
+            return self.start_price;
+        }
+        if current_block >= end_time {
+            return self.floor_price;
+        }
+
+        let spread = self.start_price.saturating_sub(self.floor_price);
+        let elapsed = (current_block - start_time) as u128;
+        let duration = (end_time - start_time) as u128;
+
+        let decayed = match self.curve {
+            DecayCurve::Linear => spread.saturating_mul(elapsed) / duration,
+            DecayCurve::Stepped { interval } => {
+                let interval = (interval as u128).max(1);
+                let elapsed = elapsed - elapsed % interval;
+                spread.saturating_mul(elapsed) / duration
+            }
+            DecayCurve::Exponential { half_life } => {
+                let half_life = (half_life as u128).max(1);
+                let halvings = elapsed / half_life;
+                let remaining = if halvings >= Amount::BITS as u128 { 0 } else { spread >> halvings };
+                // Interpolate linearly towards the next halving
+                let step = remaining - remaining / 2;
+                let remaining = remaining - step.saturating_mul(elapsed % half_life) / half_life;
+                spread - remaining
+            }
+        };
+
+        self.start_price.saturating_sub(decayed).max(self.floor_price)
+    }
+}
+
+/// Shape of a bonding curve; the price changes once per `step_size` tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum CurveKind {
+    /// Price rises by `increment` per step.
+    Linear { increment: Amount },
+    /// Price rises by `growth_bps` per step, compounded.
+    Exponential { growth_bps: BasisPoints },
+}
+
+/// Continuous sale priced along a curve of the supply sold.
+///
+/// Holders can sell back into the reserve until it reaches
+/// `target_reserve`, at which point the launch graduates.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct BondingCurve {
+    pub kind: CurveKind,
+    /// Price of the first step.
+    pub initial_price: Amount,
+    /// Tokens sold at each price.
+    pub step_size: Amount,
+    /// Reserve at which the launch graduates.
+    pub target_reserve: Amount,
+}
+
+impl BondingCurve {
+    fn next_price(&self, price: Amount) -> Amount {
+        match self.kind {
+            CurveKind::Linear { increment } => price.saturating_add(increment),
+            CurveKind::Exponential { growth_bps } => price
+                .saturating_mul(MAX_BASIS_POINTS as Amount + growth_bps as Amount)
+                / MAX_BASIS_POINTS as Amount,
+        }
+    }
+
+    /// Price of step `step`.
+    fn step_price(&self, step: Amount) -> Amount {
+        match self.kind {
+            CurveKind::Linear { increment } => self.initial_price.saturating_add(increment.saturating_mul(step)),
+            CurveKind::Exponential { .. } => (0..step).fold(self.initial_price, |price, _| self.next_price(price)),
+        }
+    }
+
+    /// Price of the next token once `sold` tokens are out.
+    pub fn spot_price(&self, sold: Amount) -> Amount {
+        self.step_price(sold / self.step_size)
+    }
+
+    /// Cost of the `tokens` following the first `from` sold.
+    pub fn cost(&self, from: Amount, tokens: Amount) -> Amount {
+        let end = from.saturating_add(tokens);
+        let mut step = from / self.step_size;
+        let mut price = self.step_price(step);
+        let mut position = from;
+        let mut cost: Amount = 0;
+
+        while position < end {
+            let chunk = end.min((step + 1).saturating_mul(self.step_size)) - position;
+            cost = cost.saturating_add(chunk.saturating_mul(price));
+            position += chunk;
+            step += 1;
+            price = self.next_price(price);
+        }
+
+        cost
+    }
+
+    /// Tokens `budget` buys once `sold` tokens are out, up to `max_tokens`.
+    ///
+    /// Returns `(tokens, cost)`.
+    pub fn buy(&self, sold: Amount, budget: Amount, max_tokens: Amount) -> (Amount, Amount) {
+        let end = sold.saturating_add(max_tokens);
+        let mut step = sold / self.step_size;
+        let mut price = self.step_price(step);
+        let mut position = sold;
+        let mut cost: Amount = 0;
+
+        while position < end && price > 0 {
+            let chunk = end.min((step + 1).saturating_mul(self.step_size)) - position;
+            let affordable = chunk.min((budget - cost) / price);
+            cost += affordable * price;
+            position += affordable;
+            if affordable < chunk {
+                break;
+            }
+            step += 1;
+            price = self.next_price(price);
+        }
+
+        (position - sold, cost)
+    }
+}
+
+/// Share of a successful raise paired with tokens in a DEX pool.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct LiquidityConfig {
+    /// Share of `total_raised` seeded, in basis points.
+    pub raise_bps: BasisPoints,
+    /// Blocks the pool shares stay locked in the launchpad after seeding.
+    pub lock_duration: BlockNumber,
+}
+
+/// Liquidity a launch added to the DEX.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct SeededLiquidity {
+    pub dex: ActorId,
+    /// Payment currency added to the pool.
+    pub funds: Amount,
+    /// Sale tokens added to the pool.
+    pub tokens: Amount,
+    /// Pool liquidity minted in return.
+    pub liquidity: Amount,
+    pub seeded_at: BlockNumber,
+}
+
+/// Pool shares held by the launchpad on behalf of a launch's creator.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct LiquidityLock {
+    pub dex: ActorId,
+    /// Sale token of the pool.
+    pub token: ActorId,
+    /// Quote side of the pool (`None` = native VARA).
+    pub quote: Option<ActorId>,
+    /// Pool liquidity locked.
+    pub liquidity: Amount,
+    pub locked_at: BlockNumber,
+    /// First block at which the creator can withdraw.
+    pub unlock_at: BlockNumber,
+    pub withdrawn: bool,
+}
+
+/// Share of a team allocation.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct TeamShare {
+    pub beneficiary: ActorId,
+    /// Share of the allocation in basis points.
+    pub share_bps: BasisPoints,
+}
+
+/// Tokens minted for the creator and team, vested on their own schedule.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct TeamAllocation {
+    /// Tokens set aside on top of `total_tokens`.
+    pub total: Amount,
+    pub vesting: VestingConfig,
+    /// Shares summing to 10000 bps.
+    pub beneficiaries: Vec<TeamShare>,
+}
+
+/// Unvested remainder of a team schedule taken back by the creator or an admin.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct TeamRevocation {
+    /// Block at which vesting stopped.
+    pub at_block: BlockNumber,
+    /// Tokens that will no longer vest.
+    pub revoked: Amount,
+    /// Whether they were burned rather than returned to the creator.
+    pub burned: bool,
+}
+
+/// Settlement state of an outbound VFT transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum TransferStatus {
+    /// Sent, awaiting the token's reply.
+    Pending,
+    /// The token rejected the transfer; it can be re-sent with `retry_transfer`.
+    Failed,
+}
+
+/// Outbound VFT transfer recorded before it is sent.
+///
+/// State is committed before the send, so the entry is the record of what
+/// is still owed; it is removed once the token confirms the transfer.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct PendingTransfer {
+    /// Launch the transfer settles (`None` for rescues).
+    pub launch_id: Option<Id>,
+    pub token: ActorId,
+    pub to: ActorId,
+    pub amount: Amount,
+    pub status: TransferStatus,
+    /// Sends so far, including the first.
+    pub attempts: u32,
+    pub created_at: BlockNumber,
+}
+
+/// Step performed by a launch's scheduled self-message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ScheduledAction {
+    /// Activate the launch at `start_time`.
+    Start,
+    /// Finalize the sale once it can close.
+    Finalize,
+}
+
+/// Next automated step of a launch.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct LaunchSchedule {
+    pub action: ScheduledAction,
+    /// Block the step is delivered at.
+    pub due_block: BlockNumber,
+    /// Delayed message carrying the step.
+    pub message_id: MessageId,
+}
+
+impl TeamAllocation {
+    /// Split `total` by share; rounding dust goes to the first beneficiary.
+    pub fn allocations(&self) -> BTreeMap<ActorId, Amount> {
+        let mut allocations = BTreeMap::new();
+        let mut allocated: Amount = 0;
+        for share in &self.beneficiaries {
+            let amount = self.total
+                .saturating_mul(share.share_bps as Amount)
+                .checked_div(MAX_BASIS_POINTS as Amount)
+                .unwrap_or(0);
+            allocated = allocated.saturating_add(amount);
+            allocations.insert(share.beneficiary, amount);
+        }
+        if let Some(first) = self.beneficiaries.first() {
+            *allocations.entry(first.beneficiary).or_default() += self.total.saturating_sub(allocated);
+        }
+        allocations
+    }
 }
 
-// Implement SailsEvent trait for event emission
-impl sails_rs::SailsEvent for LaunchpadEvent {
-    fn encoded_event_name(&self) -> &'static [u8] {
-        match self {
-            LaunchpadEvent::LaunchCreated { .. } => b"LaunchCreated",
-            LaunchpadEvent::LaunchStarted { .. } => b"LaunchStarted",
-            LaunchpadEvent::SaleEnded { .. } => b"SaleEnded",
-            LaunchpadEvent::SaleFullySubscribed { .. } => b"SaleFullySubscribed",
-            LaunchpadEvent::LaunchSucceeded { .. } => b"LaunchSucceeded",
-            LaunchpadEvent::LaunchFailed { .. } => b"LaunchFailed",
-            LaunchpadEvent::LaunchCancelled { .. } => b"LaunchCancelled",
-            LaunchpadEvent::DistributionPending { .. } => b"DistributionPending",
-            LaunchpadEvent::RefundsAvailable { .. } => b"RefundsAvailable",
-            LaunchpadEvent::Contributed { .. } => b"Contributed",
-            LaunchpadEvent::TokensClaimed { .. } => b"TokensClaimed",
-            LaunchpadEvent::TokenTransferFailed { .. } => b"TokenTransferFailed",
-            LaunchpadEvent::RefundClaimed { .. } => b"RefundClaimed",
-            LaunchpadEvent::FundsWithdrawn { .. } => b"FundsWithdrawn",
-            LaunchpadEvent::FeesWithdrawn { .. } => b"FeesWithdrawn",
-            LaunchpadEvent::WhitelistUpdated { .. } => b"WhitelistUpdated",
-            LaunchpadEvent::TokensDeposited { .. } => b"TokensDeposited",
-            LaunchpadEvent::LaunchFinalized { .. } => b"LaunchFinalized",
-            LaunchpadEvent::Paused { .. } => b"Paused",
-            LaunchpadEvent::Resumed { .. } => b"Resumed",
-            LaunchpadEvent::FeeRecipientUpdated { .. } => b"FeeRecipientUpdated",
-            LaunchpadEvent::AdminForceRefund { .. } => b"AdminForceRefund",
-            LaunchpadEvent::TokensRescued { .. } => b"TokensRescued",
-            LaunchpadEvent::TokenDeployed { .. } => b"TokenDeployed",
-            LaunchpadEvent::GasConfigUpdated { .. } => b"GasConfigUpdated",
+impl LiquidityLock {
+    /// Check if the lock has expired at `block`.
+    pub fn is_unlocked(&self, block: BlockNumber) -> bool {
+        block >= self.unlock_at
+    }
+}
+
+/// Pushes the end of a sale back when contributions arrive in its final
+/// blocks, so buyers cannot wait for the last block.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct AntiSnipingConfig {
+    /// Contributions within this many blocks of `end_time` extend the sale.
+    pub window: BlockNumber,
+    /// Blocks added per extending contribution.
+    pub extension: BlockNumber,
+    /// Most blocks anti-sniping may add in total.
+    pub max_extension: BlockNumber,
+}
+
+/// Input for creating a new launch.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct CreateLaunchInput {
+    // Token creation parameters
+    pub token_name: String,
+    pub token_symbol: String,
+    /// Existing VFT to sell instead of deploying a new one. The creator must
+    /// then call `deposit_tokens` before the launch can start.
+    pub token_address: Option<ActorId>,
+    
+    // Launch parameters
+    pub title: String,
+    pub description: String,
+    pub total_tokens: Amount,
+    pub price_per_token: Amount,
+    pub min_raise: Amount,
+    pub max_raise: Amount,
+    pub max_per_wallet: Amount,
+    pub start_time: BlockNumber,
+    pub end_time: BlockNumber,
+    /// Extend the sale when contributions land in its final blocks.
+    pub anti_sniping: Option<AntiSnipingConfig>,
+    /// Blocks the creator may add once if `min_raise` is not met (0 = disabled).
+    pub soft_cap_extension: BlockNumber,
+    pub whitelist_enabled: bool,
+    /// Merkle allowlist root, an alternative to `whitelist_enabled` for large lists.
+    pub whitelist_root: Option<Hash>,
+    /// Sale phases in chronological order; leave empty for a single round.
+    pub phases: Vec<SalePhase>,
+    /// Dutch auction pricing; `price_per_token` is ignored when set.
+    pub auction: Option<DutchAuction>,
+    /// Oversubscription mode: accept demand beyond `max_raise` and allocate pro-rata.
+    pub pro_rata: bool,
+    /// Bonding-curve pricing; `price_per_token` is ignored when set.
+    pub bonding_curve: Option<BondingCurve>,
+    /// Seed a share of the raise into the DEX on success.
+    pub liquidity: Option<LiquidityConfig>,
+    pub vesting_config: Option<VestingConfig>,
+    /// Let holders transfer their purchased positions (the creator can toggle it later).
+    pub positions_transferable: bool,
+    /// Optional creator/team allocation minted on top of the sale.
+    pub team_allocation: Option<TeamAllocation>,
+    /// Currency to raise in; `TokenType::Vft` launches take contributions via `contribute_vft`.
+    pub payment_token: TokenType,
+    /// Milestones releasing the raise in tranches; amounts are basis points summing to 10_000.
+    /// Leave empty to release all funds at once.
+    pub milestones: Vec<Milestone>,
+    /// Enables contributor governance with the given quorum and threshold.
+    pub governance: Option<GovernanceConfig>,
+}
+
+/// Contributor governance settings for a launch.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct GovernanceConfig {
+    /// Share of the raise that must vote for a result to count.
+    pub quorum_bps: BasisPoints,
+    /// Share of for + against weight that must vote for a proposal to pass.
+    pub approval_threshold_bps: BasisPoints,
+    /// Voting period in blocks.
+    pub voting_period: BlockNumber,
+}
+
+/// Action a contributor proposal executes when it passes.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum ProposalAction {
+    /// Cancel the live launch and open refunds.
+    CancelLaunch,
+    /// Move the launch end time to the given block.
+    ExtendEndTime(BlockNumber),
+    /// Complete the milestone at the given index.
+    ApproveMilestone(u32),
+}
+
+/// Contributor proposal, voted on with contribution weight.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct Proposal {
+    pub id: u32,
+    pub launch_id: Id,
+    pub proposer: ActorId,
+    pub action: ProposalAction,
+    pub votes_for: Amount,
+    pub votes_against: Amount,
+    pub votes_abstain: Amount,
+    /// Choice made by each voter.
+    pub voters: BTreeMap<ActorId, VoteChoice>,
+    /// Last block votes are accepted.
+    pub voting_ends: BlockNumber,
+    /// Active while voting, then Completed (executed) or Failed (rejected).
+    pub status: Status,
+}
+
+impl Proposal {
+    /// Check quorum and approval threshold against `total_weight`.
+    pub fn passed(&self, config: &GovernanceConfig, total_weight: Amount) -> bool {
+        let cast = self.votes_for
+            .saturating_add(self.votes_against)
+            .saturating_add(self.votes_abstain);
+        let quorum_met = cast.saturating_mul(MAX_BASIS_POINTS as Amount)
+            >= total_weight.saturating_mul(config.quorum_bps as Amount);
+
+        let decided = self.votes_for.saturating_add(self.votes_against);
+        let threshold_met = decided > 0
+            && self.votes_for.saturating_mul(MAX_BASIS_POINTS as Amount)
+                >= decided.saturating_mul(config.approval_threshold_bps as Amount);
+
+        quorum_met && threshold_met
+    }
+}
+
+// =============================================================================
+// STORAGE
+// =============================================================================
+
+/// Storage for the Launchpad contract.
+///
+/// A single instance is owned by [`LaunchpadProgram`] and lent to every
+/// service call, so commands and queries always observe the same state.
+#[derive(Default)]
+pub struct LaunchpadStorage {
+    launches: BTreeMap<Id, Launch>,
+    next_launch_id: Id,
+    owner: ActorId,
+    /// Proposed owner awaiting `accept_ownership`.
+    pending_owner: Option<ActorId>,
+    /// Accounts holding each access-control role.
+    roles: BTreeMap<RoleId, BTreeSet<ActorId>>,
+    /// Platform fee in basis points (100 = 1%).
+    fee_basis_points: u16,
+    /// Total accumulated fees.
+    accumulated_fees: Amount,
+    /// Total fees withdrawn.
+    fees_withdrawn: Amount,
+    /// Accumulated fees per VFT payment token.
+    token_fees: BTreeMap<ActorId, Amount>,
+    /// Fees withdrawn per VFT payment token.
+    token_fees_withdrawn: BTreeMap<ActorId, Amount>,
+    /// Governance proposals per launch, indexed by proposal ID.
+    proposals: BTreeMap<Id, Vec<Proposal>>,
+    /// Paused state.
+    paused: bool,
+    /// Code ID of the VFT token contract for deployment.
+    vft_code_id: CodeId,
+    /// Reentrancy guard to prevent recursive calls.
+    reentrancy_guard: bool,
+    /// Launches with a `distribute_batch` or `refund_batch` call in flight.
+    batch_locks: BTreeSet<Id>,
+    /// Fee recipient address (defaults to owner).
+    fee_recipient: ActorId,
+    /// Gas allocated for program creation.
+    gas_for_program: u64,
+    /// Gas allocated for reply handling.
+    gas_for_reply: u64,
+    /// Reply deposit for each tracked native payout.
+    gas_for_native_reply: u64,
+    /// DEX that receives liquidity seeded by successful launches.
+    dex: Option<ActorId>,
+    /// Shortest liquidity lock a launch may configure, in blocks.
+    min_liquidity_lock: BlockNumber,
+    /// Blocks after `end_time` to seed a launch's liquidity before its share
+    /// can be released to the creator.
+    liquidity_seeding_period: BlockNumber,
+    /// Pool shares locked per launch.
+    liquidity_locks: BTreeMap<Id, LiquidityLock>,
+    /// Addresses contributors redirected their payouts to.
+    claim_addresses: BTreeMap<ActorId, ActorId>,
+    /// Unsettled outbound VFT transfers, by transfer ID.
+    pending_transfers: BTreeMap<Id, PendingTransfer>,
+    next_transfer_id: Id,
+    /// Native payouts awaiting their reply, by sent message.
+    /// Each entry is `(user owed, recipient, amount)`.
+    native_sends: BTreeMap<MessageId, (ActorId, ActorId, Amount)>,
+    /// Undelivered native payouts, collected with `withdraw_pending`.
+    pending_withdrawals: BTreeMap<ActorId, Amount>,
+    /// Gas for each scheduled activation and finalization message.
+    gas_for_automation: u64,
+    /// Pending automated step per launch.
+    schedules: BTreeMap<Id, LaunchSchedule>,
+    /// Gas reserved per launch for its finalization message, until sent.
+    reservations: BTreeMap<Id, gstd::Reservation>,
+    /// Scheduled messages awaiting their reply, by message.
+    scheduled_messages: BTreeMap<MessageId, Id>,
+}
+
+impl LaunchpadStorage {
+    /// Create storage for a freshly deployed launchpad.
+    pub fn new(owner: ActorId, fee_basis_points: u16) -> Self {
+        let mut storage = Self {
+            owner,
+            fee_basis_points,
+            // Default to Gear's standard VFT code ID
+            // This can be updated via set_vft_code_id() after deployment
+            vft_code_id: CodeId::default(),
+            // Fee recipient defaults to owner
+            fee_recipient: owner,
+            // Default gas values for program creation
+            gas_for_program: 10_000_000_000, // 10 billion
+            gas_for_reply: 5_000_000_000,    // 5 billion
+            gas_for_native_reply: 5_000_000_000, // 5 billion
+            gas_for_automation: 20_000_000_000, // 20 billion
+            min_liquidity_lock: 1,
+            liquidity_seeding_period: 2_592_000, // ~30 days at 1 block/sec
+            ..Default::default()
+        };
+
+        // The deployer starts with every role
+        for role in roles::ALL {
+            storage.grant_role(role, owner);
+        }
+
+        storage
+    }
+
+    /// Check if `account` holds `role`.
+    fn has_role(&self, role: RoleId, account: &ActorId) -> bool {
+        self.roles.get(&role).is_some_and(|members| members.contains(account))
+    }
+
+    /// Where payouts owed to `user` are sent: their claim address, if registered.
+    fn payout_address(&self, user: &ActorId) -> ActorId {
+        self.claim_addresses.get(user).copied().unwrap_or(*user)
+    }
+
+    /// Send native value owed to `user` to `to`, tracking the message until
+    /// its reply arrives.
+    ///
+    /// A send that cannot be queued is credited to `user`'s pending
+    /// withdrawals right away; a bounced one is credited by `settle_native_reply`.
+    /// Fails before sending if the message cannot pay the reply deposit, as
+    /// an untracked payout could bounce unnoticed.
+    fn send_native(&mut self, user: ActorId, to: ActorId, amount: Amount) -> Result<(), ContractError> {
+        if amount == 0 {
+            return Ok(());
+        }
+        if gstd::exec::gas_available() <= self.gas_for_native_reply {
+            return Err(ContractError::invalid_state("Not enough gas for the payout's reply deposit"));
+        }
+        match gstd::msg::send_bytes(to, [], amount) {
+            Ok(message_id) => {
+                // The deposit pays for handling the reply, including the
+                // error reply that brings undelivered value back
+                gstd::exec::reply_deposit(message_id, self.gas_for_native_reply)
+                    .map_err(|_| ContractError::invalid_state("Failed to deposit gas for the payout's reply"))?;
+                self.native_sends.insert(message_id, (user, to, amount));
+            }
+            Err(_) => self.credit_pending(user, amount),
+        }
+        Ok(())
+    }
+
+    /// Settle the native payout the current reply answers.
+    ///
+    /// Returns the user owed, the recipient and the amount if the payout bounced.
+    fn settle_native_reply(&mut self) -> Option<(ActorId, ActorId, Amount)> {
+        let message_id = gstd::msg::reply_to().ok()?;
+        let (user, to, amount) = self.native_sends.remove(&message_id)?;
+        let bounced = gstd::msg::reply_code().map_or(true, |code| code.is_error());
+        if !bounced {
+            return None;
+        }
+        self.credit_pending(user, amount);
+        Some((user, to, amount))
+    }
+
+    fn credit_pending(&mut self, to: ActorId, amount: Amount) {
+        let pending = self.pending_withdrawals.entry(to).or_insert(0);
+        *pending = pending.saturating_add(amount);
+    }
+
+    /// Send the self-message for `launch_id`'s next automated step, delivered
+    /// at `due_block`.
+    ///
+    /// Activation carries `gas_for_automation` from the current message and
+    /// the first finalization is paid from the launch's reservation. A
+    /// finalization that has to wait longer, or whose reservation expired,
+    /// carries on with the current message's remaining gas.
+    fn schedule_step(&mut self, launch_id: Id, action: ScheduledAction, due_block: BlockNumber) -> Result<(), ContractError> {
+        let program = gstd::exec::program_id();
+        let payload = ("Launchpad", "RunAutomation", launch_id).encode();
+        let delay = due_block.saturating_sub(gstd::exec::block_height());
+
+        let sent = match action {
+            ScheduledAction::Start => {
+                gstd::msg::send_bytes_with_gas_delayed(program, &payload, self.gas_for_automation, 0, delay)
+            }
+            ScheduledAction::Finalize => match self.reservations.remove(&launch_id) {
+                Some(reservation) => {
+                    gstd::msg::send_bytes_delayed_from_reservation(reservation.id(), program, &payload, 0, delay)
+                        .or_else(|_| gstd::msg::send_bytes_delayed(program, &payload, 0, delay))
+                }
+                None => gstd::msg::send_bytes_delayed(program, &payload, 0, delay),
+            },
+        };
+        let message_id = sent.map_err(|_| ContractError::invalid_state("Failed to schedule message"))?;
+
+        // The deposit lets a failed step report back through its error reply
+        let _ = gstd::exec::reply_deposit(message_id, self.gas_for_reply);
+        self.scheduled_messages.insert(message_id, launch_id);
+        self.schedules.insert(launch_id, LaunchSchedule { action, due_block, message_id });
+        Ok(())
+    }
+
+    /// Drop `launch_id`'s schedule and release any unused reservation.
+    fn clear_schedule(&mut self, launch_id: Id) -> Option<LaunchSchedule> {
+        if let Some(reservation) = self.reservations.remove(&launch_id) {
+            let _ = reservation.unreserve();
+        }
+        self.schedules.remove(&launch_id)
+    }
+
+    /// Settle the scheduled step the current reply answers.
+    ///
+    /// Returns the launch and its dropped schedule if the step failed
+    /// before replacing itself.
+    fn settle_scheduled_reply(&mut self) -> Option<(Id, LaunchSchedule)> {
+        let message_id = gstd::msg::reply_to().ok()?;
+        let launch_id = self.scheduled_messages.remove(&message_id)?;
+        let failed = gstd::msg::reply_code().map_or(true, |code| code.is_error());
+        let pending = self.schedules.get(&launch_id).is_some_and(|schedule| schedule.message_id == message_id);
+        if !failed || !pending {
+            return None;
+        }
+        self.clear_schedule(launch_id).map(|schedule| (launch_id, schedule))
+    }
+
+    /// Fail with `Unauthorized` unless `account` holds `role`.
+    fn ensure_role(&self, role: RoleId, account: &ActorId) -> Result<(), ContractError> {
+        if self.has_role(role, account) {
+            Ok(())
+        } else {
+            Err(ContractError::Unauthorized)
+        }
+    }
+
+    /// Give `role` to `account`. Returns false if it was already held.
+    fn grant_role(&mut self, role: RoleId, account: ActorId) -> bool {
+        self.roles.entry(role).or_default().insert(account)
+    }
+
+    /// Hand ownership and every role the current owner holds to `new_owner`.
+    ///
+    /// A zero `new_owner` renounces ownership and clears all roles.
+    fn transfer_ownership(&mut self, new_owner: ActorId) {
+        let previous_owner = self.owner;
+        self.owner = new_owner;
+        self.pending_owner = None;
+
+        if new_owner == ActorId::zero() {
+            self.roles.clear();
+            return;
+        }
+
+        for members in self.roles.values_mut() {
+            if members.remove(&previous_owner) {
+                members.insert(new_owner);
+            }
+        }
+    }
+
+    /// Take `role` from `account`. Returns false if it was not held.
+    ///
+    /// The last `DEFAULT_ADMIN` cannot be removed, so roles stay manageable.
+    fn revoke_role(&mut self, role: RoleId, account: &ActorId) -> Result<bool, ContractError> {
+        let Some(members) = self.roles.get_mut(&role) else {
+            return Ok(false);
+        };
+
+        if role == RoleId::DEFAULT_ADMIN && members.len() == 1 && members.contains(account) {
+            return Err(ContractError::invalid_state("Cannot remove the last admin"));
+        }
+
+        Ok(members.remove(account))
+    }
+
+    /// Record a platform fee in the given currency.
+    fn accrue_fee(&mut self, payment_token: TokenType, fee: Amount) {
+        match payment_token {
+            TokenType::Native => self.accumulated_fees = self.accumulated_fees.saturating_add(fee),
+            TokenType::Vft(token_address) => {
+                let accumulated = self.token_fees.entry(token_address).or_insert(0);
+                *accumulated = accumulated.saturating_add(fee);
+            }
+        }
+    }
+
+    /// Fees collected in a VFT payment token that have not been withdrawn yet.
+    fn available_token_fees(&self, token_address: &ActorId) -> Amount {
+        let accumulated = self.token_fees.get(token_address).copied().unwrap_or(0);
+        let withdrawn = self.token_fees_withdrawn.get(token_address).copied().unwrap_or(0);
+        accumulated.saturating_sub(withdrawn)
+    }
+}
+
+// =============================================================================
+// SECURITY HELPERS
+// =============================================================================
+
+/// Reentrancy guard to prevent recursive calls.
+struct ReentrancyGuard<'a>(&'a RefCell<LaunchpadStorage>);
+
+impl<'a> ReentrancyGuard<'a> {
+    /// Start a guarded operation.
+    fn start(storage: &'a RefCell<LaunchpadStorage>) -> Result<Self, ContractError> {
+        let mut s = storage.borrow_mut();
+        if s.reentrancy_guard {
+            return Err(ContractError::invalid_state("Reentrant call"));
+        }
+        s.reentrancy_guard = true;
+        Ok(ReentrancyGuard(storage))
+    }
+}
+
+impl Drop for ReentrancyGuard<'_> {
+    fn drop(&mut self) {
+        self.0.borrow_mut().reentrancy_guard = false;
+    }
+}
+
+/// Per-launch lock for batch payouts, which await once per contributor and
+/// so must not hold the global `ReentrancyGuard`.
+struct BatchGuard<'a>(&'a RefCell<LaunchpadStorage>, Id);
+
+impl<'a> BatchGuard<'a> {
+    /// Lock `launch_id` for a batch.
+    fn start(storage: &'a RefCell<LaunchpadStorage>, launch_id: Id) -> Result<Self, ContractError> {
+        if !storage.borrow_mut().batch_locks.insert(launch_id) {
+            return Err(ContractError::invalid_state("Batch already in progress"));
+        }
+        Ok(BatchGuard(storage, launch_id))
+    }
+}
+
+impl Drop for BatchGuard<'_> {
+    fn drop(&mut self) {
+        self.0.borrow_mut().batch_locks.remove(&self.1);
+    }
+}
+
+// =============================================================================
+// HELPERS
+// =============================================================================
+
+/// Contributors `start..start + count` (clamped to the list) and the end index.
+fn batch_page(contributors: &[ActorId], start: u32, count: u32) -> Result<(&[ActorId], u32), ContractError> {
+    if count == 0 || count > MAX_BATCH_SIZE {
+        return Err(ContractError::invalid_input("Batch size must be 1-50"));
+    }
+    let len = contributors.len() as u32;
+    if start >= len {
+        return Err(ContractError::invalid_input("Batch start out of range"));
+    }
+    let end = start.saturating_add(count).min(len);
+    Ok((&contributors[start as usize..end as usize], end))
+}
+
+/// Full 256-bit product of two amounts (it cannot overflow).
+fn wide_mul(a: Amount, b: Amount) -> WideU256 {
+    WideU256::from(a).overflowing_mul(WideU256::from(b)).0
+}
+
+/// `a * b / den` with a 256-bit intermediate product (zero if `den` is zero).
+fn mul_div(a: Amount, b: Amount, den: Amount) -> Amount {
+    wide_mul(a, b)
+        .checked_div(WideU256::from(den))
+        .map_or(0, |quotient| Amount::try_from(quotient).unwrap_or(Amount::MAX))
+}
+
+/// Cursor after a page ending at `end`: back to 0 once the list is done, so
+/// later rounds (newly vested tokens, retried refunds) start over.
+fn next_cursor(contributors: &[ActorId], end: u32) -> u32 {
+    if end as usize >= contributors.len() {
+        0
+    } else {
+        end
+    }
+}
+
+/// Human-readable reason for an error reported through an event.
+fn error_reason(err: ContractError) -> String {
+    match err {
+        ContractError::InvalidInput(reason) | ContractError::InvalidState(reason) => reason,
+        other => alloc::format!("{other:?}"),
+    }
+}
+
+/// Tokens of `total_tokens` vested at `current_block`: the TGE share plus
+/// what the curve has released of the rest.
+fn calculate_vested_tokens(
+    total_tokens: Amount,
+    vesting: &VestingConfig,
+    current_block: BlockNumber,
+) -> Amount {
+    // Before TGE - nothing vested
+    if current_block < vesting.start_block {
+        return 0;
+    }
+
+    // The TGE share unlocks at start; the curve releases the rest
+    let tge_tokens = mul_div(total_tokens, vesting.tge_unlock_bps as Amount, MAX_BASIS_POINTS as Amount);
+    let remaining = total_tokens.saturating_sub(tge_tokens);
+
+    let released = match vesting.curve {
+        VestingCurve::Linear => linear_vested_tokens(remaining, vesting, current_block),
+        VestingCurve::Stepped { interval } => {
+            // Count only whole steps, except once vesting has ended
+            let elapsed = current_block.saturating_sub(vesting.start_block);
+            let step_block = current_block.saturating_sub(elapsed.checked_rem(interval).unwrap_or(0));
+            if current_block >= vesting.vesting_end() {
+                remaining
+            } else {
+                linear_vested_tokens(remaining, vesting, step_block)
+            }
+        }
+        VestingCurve::Custom(ref points) => {
+            if current_block < vesting.cliff_end() {
+                0
+            } else {
+                let unlocked_bps = points
+                    .iter()
+                    .take_while(|(block, _)| *block <= current_block)
+                    .last()
+                    .map_or(0, |&(_, bps)| bps);
+                mul_div(remaining, unlocked_bps as Amount, MAX_BASIS_POINTS as Amount)
+            }
+        }
+    };
+
+    tge_tokens.saturating_add(released)
+}
+
+/// Cliff-plus-linear release of `total_tokens`.
+fn linear_vested_tokens(
+    total_tokens: Amount,
+    vesting: &VestingConfig,
+    current_block: BlockNumber,
+) -> Amount {
+    // Before cliff - nothing vested
+    let cliff_end = vesting.cliff_end();
+    if current_block < cliff_end {
+        return 0;
+    }
+
+    // After vesting end - everything vested
+    let vesting_end = vesting.vesting_end();
+    if current_block >= vesting_end {
+        return total_tokens;
+    }
+
+    // During vesting - linear interpolation with a 256-bit product
+    let vesting_duration = vesting.vesting_duration as Amount;
+    if vesting_duration == 0 {
+        return total_tokens;
+    }
+
+    let elapsed = current_block.saturating_sub(vesting.start_block) as Amount;
+    mul_div(total_tokens, elapsed, vesting_duration)
+}
+
+// =============================================================================
+// EVENTS
+// =============================================================================
+
+/// Events emitted by the Launchpad contract.
+#[derive(Debug, Clone, Encode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum LaunchpadEvent {
+    /// New launch created.
+    LaunchCreated {
+        launch_id: Id,
+        creator: ActorId,
+        title: String,
+        token_address: ActorId,
+        total_tokens: Amount,
+        price_per_token: Amount,
+        payment_token: TokenType,
+        min_raise: Amount,
+        max_raise: Amount,
+        start_time: BlockNumber,
+        end_time: BlockNumber,
+    },
+    /// Launch started and accepting contributions.
+    LaunchStarted {
+        launch_id: Id,
+    },
+    /// Sale ended (time expired or fully subscribed).
+    SaleEnded {
+        launch_id: Id,
+        total_raised: Amount,
+        total_contributors: u32,
+        reason: String,
+    },
+    /// Sale fully subscribed before end time.
+    SaleFullySubscribed {
+        launch_id: Id,
+        total_raised: Amount,
+    },
+    /// Launch succeeded (min raise met).
+    LaunchSucceeded {
+        launch_id: Id,
+        total_raised: Amount,
+    },
+    /// Launch failed (min raise not met).
+    LaunchFailed {
+        launch_id: Id,
+        total_raised: Amount,
+        min_raise: Amount,
+    },
+    /// Launch cancelled by creator.
+    LaunchCancelled {
+        launch_id: Id,
+        by: ActorId,
+    },
+    /// Distribution phase started.
+    DistributionPending {
+        launch_id: Id,
+    },
+    /// Refunds available.
+    RefundsAvailable {
+        launch_id: Id,
+        total_to_refund: Amount,
+        num_contributors: u32,
+    },
+    /// User contributed to launch.
+    Contributed {
+        launch_id: Id,
+        contributor: ActorId,
+        amount: Amount,
+        tokens_purchased: Amount,
+        /// Live price per token the contribution was filled at.
+        price_per_token: Amount,
+        refunded: Amount,
+    },
+    /// Tokens claimed by contributor.
+    TokensClaimed {
+        launch_id: Id,
+        user: ActorId,
+        amount: Amount,
+    },
+    /// Unvested team tokens revoked, then returned to the creator or burned.
+    TeamVestingRevoked {
+        launch_id: Id,
+        beneficiary: ActorId,
+        at_block: BlockNumber,
+        revoked: Amount,
+        burned: bool,
+    },
+    /// Position transfers enabled or disabled by the creator.
+    PositionTransfersUpdated {
+        launch_id: Id,
+        enabled: bool,
+    },
+    /// Unclaimed tokens moved to another holder.
+    PositionTransferred {
+        launch_id: Id,
+        from: ActorId,
+        to: ActorId,
+        amount: Amount,
+    },
+    /// Payout address registered (`Some`) or cleared (`None`).
+    ClaimAddressUpdated {
+        user: ActorId,
+        claim_address: Option<ActorId>,
+    },
+    /// Vested team tokens claimed by a beneficiary.
+    TeamTokensClaimed {
+        launch_id: Id,
+        beneficiary: ActorId,
+        amount: Amount,
+    },
+    /// Token transfer failed (for retry).
+    TokenTransferFailed {
+        launch_id: Id,
+        user: ActorId,
+        amount: Amount,
+        reason: String,
+    },
+    /// Ledger transfer rejected by the token; retry with `retry_transfer`.
+    TransferFailed {
+        transfer_id: Id,
+        token: ActorId,
+        to: ActorId,
+        amount: Amount,
+    },
+    /// Failed ledger transfer re-sent successfully.
+    TransferRetried {
+        transfer_id: Id,
+        to: ActorId,
+        amount: Amount,
+    },
+    /// Native payout bounced; credited to the pending withdrawals of the user owed.
+    NativePayoutFailed {
+        user: ActorId,
+        to: ActorId,
+        amount: Amount,
+    },
+    /// Pending native payouts sent out again.
+    PendingWithdrawn {
+        user: ActorId,
+        amount: Amount,
+    },
+    /// Refund claimed by contributor.
+    RefundClaimed {
+        launch_id: Id,
+        user: ActorId,
+        amount: Amount,
+    },
+    /// Creator withdrew raised funds.
+    FundsWithdrawn {
+        launch_id: Id,
+        creator: ActorId,
+        amount: Amount,
+        fee: Amount,
+    },
+    /// Platform fees withdrawn by owner.
+    FeesWithdrawn {
+        owner: ActorId,
+        amount: Amount,
+        total_accumulated: Amount,
+    },
+    /// Whitelist updated.
+    WhitelistUpdated {
+        launch_id: Id,
+        addresses_added: u32,
+    },
+    /// Tokens deposited by creator.
+    TokensDeposited {
+        launch_id: Id,
+        amount: Amount,
+    },
+    /// Platform fees in a VFT payment token withdrawn by owner.
+    TokenFeesWithdrawn {
+        token_address: ActorId,
+        recipient: ActorId,
+        amount: Amount,
+        total_accumulated: Amount,
+    },
+    /// Launch finalized (all operations complete).
+    LaunchFinalized {
+        launch_id: Id,
+    },
+    /// Contract paused.
+    Paused {
+        by: ActorId,
+    },
+    /// Contract resumed.
+    Resumed {
+        by: ActorId,
+    },
+    /// Fee recipient updated.
+    FeeRecipientUpdated {
+        old: ActorId,
+        new: ActorId,
+    },
+    /// Admin forced refund for stuck contribution.
+    AdminForceRefund {
+        launch_id: Id,
+        user: ActorId,
+        amount: Amount,
+    },
+    /// Rescued tokens accidentally sent to contract.
+    TokensRescued {
+        token_address: ActorId,
+        amount: U256,
+        to: ActorId,
+    },
+    /// New token deployed via the factory.
+    TokenDeployed {
+        launch_id: Id,
+        token_address: ActorId,
+        name: String,
+        symbol: String,
+        total_supply: Amount,
+    },
+    /// Gas configuration updated.
+    GasConfigUpdated {
+        gas_for_program: u64,
+        gas_for_reply: u64,
+    },
+    /// Contributor approved a milestone.
+    MilestoneApproved {
+        launch_id: Id,
+        index: u32,
+        contributor: ActorId,
+        approval_weight: Amount,
+    },
+    /// Milestone completed and its tranche unlocked.
+    MilestoneCompleted {
+        launch_id: Id,
+        index: u32,
+        tranche: Amount,
+    },
+    /// Project abandoned; unreleased funds are refundable pro-rata.
+    LaunchAbandoned {
+        launch_id: Id,
+        total_to_refund: Amount,
+    },
+    /// Contributor opened a governance proposal.
+    ProposalCreated {
+        launch_id: Id,
+        proposal_id: u32,
+        proposer: ActorId,
+        action: ProposalAction,
+        voting_ends: BlockNumber,
+    },
+    /// Contributor voted on a proposal.
+    VoteCast {
+        launch_id: Id,
+        proposal_id: u32,
+        voter: ActorId,
+        choice: VoteChoice,
+        weight: Amount,
+    },
+    /// Proposal passed and its action was applied.
+    ProposalExecuted {
+        launch_id: Id,
+        proposal_id: u32,
+        votes_for: Amount,
+        votes_against: Amount,
+        votes_abstain: Amount,
+    },
+    /// Proposal missed quorum or threshold.
+    ProposalRejected {
+        launch_id: Id,
+        proposal_id: u32,
+        votes_for: Amount,
+        votes_against: Amount,
+        votes_abstain: Amount,
+    },
+    /// Launch end time extended.
+    EndTimeExtended {
+        launch_id: Id,
+        old_end_time: BlockNumber,
+        new_end_time: BlockNumber,
+    },
+    /// Sale extended by a contribution in its final blocks.
+    AntiSnipingExtended {
+        launch_id: Id,
+        old_end_time: BlockNumber,
+        new_end_time: BlockNumber,
+    },
+    /// Creator extended a sale that missed its soft cap.
+    SoftCapExtended {
+        launch_id: Id,
+        old_end_time: BlockNumber,
+        new_end_time: BlockNumber,
+    },
+    /// Automated step scheduled as a delayed self-message.
+    AutomationScheduled {
+        launch_id: Id,
+        action: ScheduledAction,
+        due_block: BlockNumber,
+    },
+    /// Automated step could not run; the launch falls back to manual calls.
+    AutomationFailed {
+        launch_id: Id,
+        action: ScheduledAction,
+        reason: String,
+    },
+    /// Gas for scheduled messages updated.
+    AutomationGasUpdated {
+        gas_for_automation: u64,
+    },
+    /// Reply deposit for native payouts updated.
+    NativeReplyGasUpdated {
+        gas_for_native_reply: u64,
+    },
+    /// Role granted to an account.
+    RoleGranted {
+        role: RoleId,
+        account: ActorId,
+        sender: ActorId,
+    },
+    /// Role revoked from (or renounced by) an account.
+    RoleRevoked {
+        role: RoleId,
+        account: ActorId,
+        sender: ActorId,
+    },
+    /// Owner proposed a new owner.
+    OwnershipTransferStarted {
+        owner: ActorId,
+        pending_owner: ActorId,
+    },
+    /// Pending ownership transfer withdrawn.
+    OwnershipTransferCancelled {
+        owner: ActorId,
+        pending_owner: ActorId,
+    },
+    /// Ownership changed hands; a zero `new_owner` means it was renounced.
+    OwnershipTransferred {
+        previous_owner: ActorId,
+        new_owner: ActorId,
+    },
+    /// Auction settled every buyer at its clearing price.
+    AuctionSettled {
+        launch_id: Id,
+        clearing_price: Amount,
+        total_raised: Amount,
+        total_excess: Amount,
+    },
+    /// Oversubscribed sale allocated pro-rata.
+    AllocationsSettled {
+        launch_id: Id,
+        total_committed: Amount,
+        total_raised: Amount,
+        total_excess: Amount,
+    },
+    /// Holder sold bonding-curve tokens back into the reserve.
+    TokensSold {
+        launch_id: Id,
+        seller: ActorId,
+        tokens: Amount,
+        proceeds: Amount,
+    },
+    /// Bonding-curve reserve reached its target; the sale is closed.
+    LaunchGraduated {
+        launch_id: Id,
+        reserve: Amount,
+        tokens_sold: Amount,
+    },
+    /// DEX for liquidity seeding changed.
+    DexUpdated {
+        dex: Option<ActorId>,
+    },
+    /// Minimum liquidity lock duration changed.
+    MinLiquidityLockUpdated {
+        min_liquidity_lock: BlockNumber,
+    },
+    /// Share of the raise and matching tokens added to the DEX.
+    LiquiditySeeded {
+        launch_id: Id,
+        dex: ActorId,
+        funds: Amount,
+        tokens: Amount,
+        liquidity: Amount,
+        unlock_at: BlockNumber,
+    },
+    /// Liquidity seeding failed; it can be retried with `seed_liquidity`.
+    LiquiditySeedingFailed {
+        launch_id: Id,
+        reason: String,
+    },
+    /// Unseeded liquidity share and reserve released to the creator.
+    LiquidityReleased {
+        launch_id: Id,
+        funds: Amount,
+        tokens: Amount,
+    },
+    /// Liquidity seeding period changed.
+    LiquiditySeedingPeriodUpdated {
+        liquidity_seeding_period: BlockNumber,
+    },
+    /// Locked pool shares released to the creator.
+    LiquidityWithdrawn {
+        launch_id: Id,
+        creator: ActorId,
+        liquidity: Amount,
+    },
+}
+
+// Implement SailsEvent trait for event emission
+impl sails_rs::SailsEvent for LaunchpadEvent {
+    fn encoded_event_name(&self) -> &'static [u8] {
+        match self {
+            LaunchpadEvent::LaunchCreated { .. } => b"LaunchCreated",
+            LaunchpadEvent::LaunchStarted { .. } => b"LaunchStarted",
+            LaunchpadEvent::SaleEnded { .. } => b"SaleEnded",
+            LaunchpadEvent::SaleFullySubscribed { .. } => b"SaleFullySubscribed",
+            LaunchpadEvent::LaunchSucceeded { .. } => b"LaunchSucceeded",
+            LaunchpadEvent::LaunchFailed { .. } => b"LaunchFailed",
+            LaunchpadEvent::LaunchCancelled { .. } => b"LaunchCancelled",
+            LaunchpadEvent::DistributionPending { .. } => b"DistributionPending",
+            LaunchpadEvent::RefundsAvailable { .. } => b"RefundsAvailable",
+            LaunchpadEvent::Contributed { .. } => b"Contributed",
+            LaunchpadEvent::TokensClaimed { .. } => b"TokensClaimed",
+            LaunchpadEvent::ClaimAddressUpdated { .. } => b"ClaimAddressUpdated",
+            LaunchpadEvent::TeamTokensClaimed { .. } => b"TeamTokensClaimed",
+            LaunchpadEvent::TeamVestingRevoked { .. } => b"TeamVestingRevoked",
+            LaunchpadEvent::PositionTransfersUpdated { .. } => b"PositionTransfersUpdated",
+            LaunchpadEvent::PositionTransferred { .. } => b"PositionTransferred",
+            LaunchpadEvent::TokenTransferFailed { .. } => b"TokenTransferFailed",
+            LaunchpadEvent::TransferFailed { .. } => b"TransferFailed",
+            LaunchpadEvent::TransferRetried { .. } => b"TransferRetried",
+            LaunchpadEvent::NativePayoutFailed { .. } => b"NativePayoutFailed",
+            LaunchpadEvent::PendingWithdrawn { .. } => b"PendingWithdrawn",
+            LaunchpadEvent::RefundClaimed { .. } => b"RefundClaimed",
+            LaunchpadEvent::FundsWithdrawn { .. } => b"FundsWithdrawn",
+            LaunchpadEvent::FeesWithdrawn { .. } => b"FeesWithdrawn",
+            LaunchpadEvent::WhitelistUpdated { .. } => b"WhitelistUpdated",
+            LaunchpadEvent::TokensDeposited { .. } => b"TokensDeposited",
+            LaunchpadEvent::TokenFeesWithdrawn { .. } => b"TokenFeesWithdrawn",
+            LaunchpadEvent::LaunchFinalized { .. } => b"LaunchFinalized",
+            LaunchpadEvent::Paused { .. } => b"Paused",
+            LaunchpadEvent::Resumed { .. } => b"Resumed",
+            LaunchpadEvent::FeeRecipientUpdated { .. } => b"FeeRecipientUpdated",
+            LaunchpadEvent::AdminForceRefund { .. } => b"AdminForceRefund",
+            LaunchpadEvent::TokensRescued { .. } => b"TokensRescued",
+            LaunchpadEvent::TokenDeployed { .. } => b"TokenDeployed",
+            LaunchpadEvent::GasConfigUpdated { .. } => b"GasConfigUpdated",
+            LaunchpadEvent::MilestoneApproved { .. } => b"MilestoneApproved",
+            LaunchpadEvent::MilestoneCompleted { .. } => b"MilestoneCompleted",
+            LaunchpadEvent::LaunchAbandoned { .. } => b"LaunchAbandoned",
+            LaunchpadEvent::ProposalCreated { .. } => b"ProposalCreated",
+            LaunchpadEvent::VoteCast { .. } => b"VoteCast",
+            LaunchpadEvent::ProposalExecuted { .. } => b"ProposalExecuted",
+            LaunchpadEvent::ProposalRejected { .. } => b"ProposalRejected",
+            LaunchpadEvent::EndTimeExtended { .. } => b"EndTimeExtended",
+            LaunchpadEvent::AntiSnipingExtended { .. } => b"AntiSnipingExtended",
+            LaunchpadEvent::SoftCapExtended { .. } => b"SoftCapExtended",
+            LaunchpadEvent::AutomationScheduled { .. } => b"AutomationScheduled",
+            LaunchpadEvent::AutomationFailed { .. } => b"AutomationFailed",
+            LaunchpadEvent::AutomationGasUpdated { .. } => b"AutomationGasUpdated",
+            LaunchpadEvent::NativeReplyGasUpdated { .. } => b"NativeReplyGasUpdated",
+            LaunchpadEvent::RoleGranted { .. } => b"RoleGranted",
+            LaunchpadEvent::RoleRevoked { .. } => b"RoleRevoked",
+            LaunchpadEvent::OwnershipTransferStarted { .. } => b"OwnershipTransferStarted",
+            LaunchpadEvent::OwnershipTransferCancelled { .. } => b"OwnershipTransferCancelled",
+            LaunchpadEvent::OwnershipTransferred { .. } => b"OwnershipTransferred",
+            LaunchpadEvent::AuctionSettled { .. } => b"AuctionSettled",
+            LaunchpadEvent::AllocationsSettled { .. } => b"AllocationsSettled",
+            LaunchpadEvent::TokensSold { .. } => b"TokensSold",
+            LaunchpadEvent::LaunchGraduated { .. } => b"LaunchGraduated",
+            LaunchpadEvent::DexUpdated { .. } => b"DexUpdated",
+            LaunchpadEvent::MinLiquidityLockUpdated { .. } => b"MinLiquidityLockUpdated",
+            LaunchpadEvent::LiquiditySeeded { .. } => b"LiquiditySeeded",
+            LaunchpadEvent::LiquidityWithdrawn { .. } => b"LiquidityWithdrawn",
+            LaunchpadEvent::LiquiditySeedingFailed { .. } => b"LiquiditySeedingFailed",
+            LaunchpadEvent::LiquidityReleased { .. } => b"LiquidityReleased",
+            LaunchpadEvent::LiquiditySeedingPeriodUpdated { .. } => b"LiquiditySeedingPeriodUpdated",
+        }
+    }
+}
+
+// =============================================================================
+// CONTRACT METADATA
+// =============================================================================
+
+/// Contract name and version.
+pub const CONTRACT_NAME: &str = "Vara Token Launchpad";
+pub const CONTRACT_VERSION: &str = "1.0.0";
+
+/// Finalization grace period in blocks (approximately 30 days at 1 block/sec).
+/// After end_time + FINALIZATION_GRACE_PERIOD, admin can force refunds for stuck launches.
+pub const FINALIZATION_GRACE_PERIOD: BlockNumber = 2_592_000;
+
+/// Maximum number of milestones per launch.
+pub const MAX_MILESTONES: usize = 20;
+
+/// Maximum number of sale phases per launch.
+pub const MAX_PHASES: usize = 10;
+
+/// Maximum number of price steps on a bonding curve.
+pub const MAX_CURVE_STEPS: Amount = 1_000;
+
+/// Maximum number of contributors paid by one batch call.
+pub const MAX_BATCH_SIZE: u32 = 50;
+
+/// Maximum number of team allocation beneficiaries per launch.
+pub const MAX_TEAM_BENEFICIARIES: usize = 20;
+
+/// Access-control roles. `RoleId::DEFAULT_ADMIN` grants and revokes them.
+pub mod roles {
+    use vara_contracts_shared::RoleId;
+
+    /// Can pause and resume the contract.
+    pub const PAUSER: RoleId = RoleId::from_str("PAUSER");
+    /// Can set the fee recipient and withdraw platform fees.
+    pub const FEE_MANAGER: RoleId = RoleId::from_str("FEE_MANAGER");
+    /// Can change token deployment settings.
+    pub const CONFIG_ADMIN: RoleId = RoleId::from_str("CONFIG_ADMIN");
+    /// Can force refunds of stuck contributions.
+    pub const REFUND_OPERATOR: RoleId = RoleId::from_str("REFUND_OPERATOR");
+    /// Can push batched token distributions and refunds.
+    pub const KEEPER: RoleId = RoleId::from_str("KEEPER");
+
+    /// Every role, including the admin role.
+    pub const ALL: [RoleId; 6] = [RoleId::DEFAULT_ADMIN, PAUSER, FEE_MANAGER, CONFIG_ADMIN, REFUND_OPERATOR, KEEPER];
+}
+
+// =============================================================================
+// SERVICE IMPLEMENTATION
+// =============================================================================
+
+/// Launchpad Service implementation.
+pub struct LaunchpadService<'a> {
+    storage: &'a RefCell<LaunchpadStorage>,
+}
+
+impl<'a> LaunchpadService<'a> {
+    pub fn new(storage: &'a RefCell<LaunchpadStorage>) -> Self {
+        Self { storage }
+    }
+
+    /// Shared access to the program storage.
+    fn storage(&self) -> Ref<'a, LaunchpadStorage> {
+        self.storage.borrow()
+    }
+
+    /// Exclusive access to the program storage.
+    ///
+    /// The returned guard must be dropped before any `.await`, otherwise a
+    /// message processed while this one is suspended would fail to borrow.
+    fn storage_mut(&self) -> RefMut<'a, LaunchpadStorage> {
+        self.storage.borrow_mut()
+    }
+}
+
+#[sails_rs::service(events = LaunchpadEvent)]
+impl LaunchpadService<'_> {
+    // -------------------------------------------------------------------------
+    // ADMIN FUNCTIONS
+    // -------------------------------------------------------------------------
+
+    /// Pause the contract (PAUSER role).
+    #[export(unwrap_result)]
+    pub fn pause(&mut self) -> Result<(), ContractError> {
+        let caller = gstd::msg::source();
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        s.ensure_role(roles::PAUSER, &caller)?;
+
+        s.paused = true;
+        let _ = self.emit_event(LaunchpadEvent::Paused { by: caller });
+        Ok(())
+    }
+
+    /// Resume the contract (PAUSER role).
+    #[export(unwrap_result)]
+    pub fn resume(&mut self) -> Result<(), ContractError> {
+        let caller = gstd::msg::source();
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        s.ensure_role(roles::PAUSER, &caller)?;
+
+        s.paused = false;
+        let _ = self.emit_event(LaunchpadEvent::Resumed { by: caller });
+        Ok(())
+    }
+
+    /// Set the VFT code ID for token deployment (CONFIG_ADMIN role).
+    #[export(unwrap_result)]
+    pub fn set_vft_code_id(&mut self, code_id: CodeId) -> Result<(), ContractError> {
+        let caller = gstd::msg::source();
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        s.ensure_role(roles::CONFIG_ADMIN, &caller)?;
+
+        // Validate code ID is not zero/default
+        if code_id == CodeId::default() {
+            return Err(ContractError::invalid_input("Invalid VFT code ID"));
+        }
+
+        s.vft_code_id = code_id;
+        Ok(())
+    }
+
+    /// Set the fee recipient address (FEE_MANAGER role).
+    #[export(unwrap_result)]
+    pub fn set_fee_recipient(&mut self, recipient: ActorId) -> Result<(), ContractError> {
+        let caller = gstd::msg::source();
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        s.ensure_role(roles::FEE_MANAGER, &caller)?;
+
+        // Validate recipient is not zero address
+        if recipient == ActorId::default() {
+            return Err(ContractError::ZeroAddress);
+        }
+
+        let old = s.fee_recipient;
+        s.fee_recipient = recipient;
+
+        let _ = self.emit_event(LaunchpadEvent::FeeRecipientUpdated {
+            old,
+            new: recipient,
+        });
+
+        Ok(())
+    }
+
+    /// Set gas configuration for program deployment (CONFIG_ADMIN role).
+    #[export(unwrap_result)]
+    pub fn set_gas_config(&mut self, gas_for_program: u64, gas_for_reply: u64) -> Result<(), ContractError> {
+        let caller = gstd::msg::source();
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        s.ensure_role(roles::CONFIG_ADMIN, &caller)?;
+
+        // Validate gas values are reasonable (minimum 1 billion)
+        if gas_for_program < 1_000_000_000 {
+            return Err(ContractError::invalid_input("Gas for program too low (min 1 billion)"));
+        }
+        if gas_for_reply < 1_000_000_000 {
+            return Err(ContractError::invalid_input("Gas for reply too low (min 1 billion)"));
+        }
+
+        s.gas_for_program = gas_for_program;
+        s.gas_for_reply = gas_for_reply;
+
+        let _ = self.emit_event(LaunchpadEvent::GasConfigUpdated {
+            gas_for_program,
+            gas_for_reply,
+        });
+
+        Ok(())
+    }
+
+    /// Set the reply deposit for each native payout (CONFIG_ADMIN role).
+    ///
+    /// It only has to cover settling the payout when its reply arrives.
+    #[export(unwrap_result)]
+    pub fn set_native_reply_gas(&mut self, gas_for_native_reply: u64) -> Result<(), ContractError> {
+        let caller = gstd::msg::source();
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        s.ensure_role(roles::CONFIG_ADMIN, &caller)?;
+
+        if gas_for_native_reply < 100_000_000 {
+            return Err(ContractError::invalid_input("Gas for native reply too low (min 100 million)"));
+        }
+
+        s.gas_for_native_reply = gas_for_native_reply;
+
+        let _ = self.emit_event(LaunchpadEvent::NativeReplyGasUpdated { gas_for_native_reply });
+
+        Ok(())
+    }
+
+    /// Set the gas reserved for and sent with scheduled messages (CONFIG_ADMIN role).
+    #[export(unwrap_result)]
+    pub fn set_automation_gas(&mut self, gas_for_automation: u64) -> Result<(), ContractError> {
+        let caller = gstd::msg::source();
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        s.ensure_role(roles::CONFIG_ADMIN, &caller)?;
+
+        if gas_for_automation < 1_000_000_000 {
+            return Err(ContractError::invalid_input("Gas for automation too low (min 1 billion)"));
+        }
+
+        s.gas_for_automation = gas_for_automation;
+
+        let _ = self.emit_event(LaunchpadEvent::AutomationGasUpdated { gas_for_automation });
+
+        Ok(())
+    }
+
+    /// Set the DEX that receives seeded liquidity (CONFIG_ADMIN role).
+    #[export(unwrap_result)]
+    pub fn set_dex(&mut self, dex: Option<ActorId>) -> Result<(), ContractError> {
+        let caller = gstd::msg::source();
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        s.ensure_role(roles::CONFIG_ADMIN, &caller)?;
+
+        if dex == Some(ActorId::zero()) {
+            return Err(ContractError::ZeroAddress);
+        }
+
+        s.dex = dex;
+
+        let _ = self.emit_event(LaunchpadEvent::DexUpdated { dex });
+
+        Ok(())
+    }
+
+    /// Set the shortest liquidity lock new launches may configure (CONFIG_ADMIN role).
+    #[export(unwrap_result)]
+    pub fn set_min_liquidity_lock(&mut self, min_liquidity_lock: BlockNumber) -> Result<(), ContractError> {
+        let caller = gstd::msg::source();
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        s.ensure_role(roles::CONFIG_ADMIN, &caller)?;
+
+        if min_liquidity_lock == 0 {
+            return Err(ContractError::invalid_input("Minimum liquidity lock must be positive"));
+        }
+
+        s.min_liquidity_lock = min_liquidity_lock;
+
+        let _ = self.emit_event(LaunchpadEvent::MinLiquidityLockUpdated { min_liquidity_lock });
+
+        Ok(())
+    }
+
+    /// Set how long after `end_time` liquidity can be seeded before the
+    /// share may be released to the creator (CONFIG_ADMIN role).
+    #[export(unwrap_result)]
+    pub fn set_liquidity_seeding_period(&mut self, liquidity_seeding_period: BlockNumber) -> Result<(), ContractError> {
+        let caller = gstd::msg::source();
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        s.ensure_role(roles::CONFIG_ADMIN, &caller)?;
+
+        if liquidity_seeding_period == 0 {
+            return Err(ContractError::invalid_input("Liquidity seeding period must be positive"));
+        }
+
+        s.liquidity_seeding_period = liquidity_seeding_period;
+
+        let _ = self.emit_event(LaunchpadEvent::LiquiditySeedingPeriodUpdated { liquidity_seeding_period });
+
+        Ok(())
+    }
+
+    // -------------------------------------------------------------------------
+    // ACCESS CONTROL
+    // -------------------------------------------------------------------------
+
+    /// Grant a role to an account (admin only).
+    #[export(unwrap_result)]
+    pub fn grant_role(&mut self, role: RoleId, account: ActorId) -> Result<(), ContractError> {
+        let caller = gstd::msg::source();
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        s.ensure_role(RoleId::DEFAULT_ADMIN, &caller)?;
+
+        if account == ActorId::zero() {
+            return Err(ContractError::ZeroAddress);
+        }
+
+        if !s.grant_role(role, account) {
+            return Err(ContractError::AlreadyExists);
+        }
+
+        let _ = self.emit_event(LaunchpadEvent::RoleGranted {
+            role,
+            account,
+            sender: caller,
+        });
+
+        Ok(())
+    }
+
+    /// Revoke a role from an account (admin only).
+    #[export(unwrap_result)]
+    pub fn revoke_role(&mut self, role: RoleId, account: ActorId) -> Result<(), ContractError> {
+        let caller = gstd::msg::source();
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        s.ensure_role(RoleId::DEFAULT_ADMIN, &caller)?;
+
+        if !s.revoke_role(role, &account)? {
+            return Err(ContractError::NotFound);
+        }
+
+        let _ = self.emit_event(LaunchpadEvent::RoleRevoked {
+            role,
+            account,
+            sender: caller,
+        });
+
+        Ok(())
+    }
+
+    /// Give up a role held by the caller.
+    #[export(unwrap_result)]
+    pub fn renounce_role(&mut self, role: RoleId) -> Result<(), ContractError> {
+        let caller = gstd::msg::source();
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        if !s.revoke_role(role, &caller)? {
+            return Err(ContractError::NotFound);
+        }
+
+        let _ = self.emit_event(LaunchpadEvent::RoleRevoked {
+            role,
+            account: caller,
+            sender: caller,
+        });
+
+        Ok(())
+    }
+
+    /// Propose a new owner (owner only). Takes effect once they accept.
+    #[export(unwrap_result)]
+    pub fn propose_owner(&mut self, new_owner: ActorId) -> Result<(), ContractError> {
+        let caller = gstd::msg::source();
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        if caller != s.owner {
+            return Err(ContractError::Unauthorized);
+        }
+
+        if new_owner == ActorId::zero() {
+            return Err(ContractError::ZeroAddress);
+        }
+
+        if new_owner == s.owner {
+            return Err(ContractError::invalid_input("Already the owner"));
+        }
+
+        s.pending_owner = Some(new_owner);
+
+        let _ = self.emit_event(LaunchpadEvent::OwnershipTransferStarted {
+            owner: caller,
+            pending_owner: new_owner,
+        });
+
+        Ok(())
+    }
+
+    /// Accept a pending ownership transfer (proposed owner only).
+    #[export(unwrap_result)]
+    pub fn accept_ownership(&mut self) -> Result<(), ContractError> {
+        let caller = gstd::msg::source();
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        if s.pending_owner != Some(caller) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let previous_owner = s.owner;
+        s.transfer_ownership(caller);
+
+        let _ = self.emit_event(LaunchpadEvent::OwnershipTransferred {
+            previous_owner,
+            new_owner: caller,
+        });
+
+        Ok(())
+    }
+
+    /// Withdraw a pending ownership transfer (owner only).
+    #[export(unwrap_result)]
+    pub fn cancel_ownership_transfer(&mut self) -> Result<(), ContractError> {
+        let caller = gstd::msg::source();
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        if caller != s.owner {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let pending_owner = s.pending_owner.take()
+            .ok_or(ContractError::NotFound)?;
+
+        let _ = self.emit_event(LaunchpadEvent::OwnershipTransferCancelled {
+            owner: caller,
+            pending_owner,
+        });
+
+        Ok(())
+    }
+
+    /// Give up ownership and every role for good (owner only).
+    ///
+    /// Only permissionless paths such as `finalize` keep working afterwards.
+    #[export(unwrap_result)]
+    pub fn renounce_ownership(&mut self) -> Result<(), ContractError> {
+        let caller = gstd::msg::source();
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        if caller != s.owner {
+            return Err(ContractError::Unauthorized);
+        }
+
+        s.transfer_ownership(ActorId::zero());
+
+        let _ = self.emit_event(LaunchpadEvent::OwnershipTransferred {
+            previous_owner: caller,
+            new_owner: ActorId::zero(),
+        });
+
+        Ok(())
+    }
+
+    // -------------------------------------------------------------------------
+    // LAUNCH CREATION
+    // -------------------------------------------------------------------------
+
+    /// Create a new token launch.
+    ///
+    /// Deploys a fresh token through the factory, or uses `input.token_address`
+    /// when the creator brings an existing VFT.
+    #[export(unwrap_result)]
+    pub async fn create_launch(&mut self, input: CreateLaunchInput) -> Result<Id, ContractError> {
+        let _guard = ReentrancyGuard::start(self.storage)?;
+
+        if self.storage().paused {
+            return Err(ContractError::invalid_state("Contract is paused"));
+        }
+
+        let creator = gstd::msg::source();
+        let current_block = gstd::exec::block_height();
+
+        // Validate input parameters
+        match input.token_address {
+            Some(token_address) => {
+                if token_address == ActorId::zero() {
+                    return Err(ContractError::ZeroAddress);
+                }
+                if input.payment_token == TokenType::Vft(token_address) {
+                    return Err(ContractError::invalid_input("Sale token cannot be the payment token"));
+                }
+            }
+            None => {
+                if input.token_name.is_empty() {
+                    return Err(ContractError::invalid_input("Token name cannot be empty"));
+                }
+                if input.token_name.len() > 64 {
+                    return Err(ContractError::invalid_input("Token name too long (max 64 chars)"));
+                }
+                if input.token_symbol.is_empty() {
+                    return Err(ContractError::invalid_input("Token symbol cannot be empty"));
+                }
+                if input.token_symbol.len() > 10 {
+                    return Err(ContractError::invalid_input("Token symbol too long (max 10 chars)"));
+                }
+            }
+        }
+        if input.title.is_empty() {
+            return Err(ContractError::invalid_input("Title cannot be empty"));
+        }
+        if input.total_tokens == 0 {
+            return Err(ContractError::invalid_input("Total tokens must be > 0"));
+        }
+        // An auction or bonding curve starts at its own initial price
+        let price_per_token = match (&input.auction, &input.bonding_curve) {
+            (Some(auction), _) => auction.start_price,
+            (None, Some(curve)) => curve.initial_price,
+            (None, None) => input.price_per_token,
+        };
+        if price_per_token == 0 {
+            return Err(ContractError::invalid_input("Price per token must be > 0"));
+        }
+        if input.start_time >= input.end_time {
+            return Err(ContractError::invalid_input("Start time must be before end time"));
+        }
+        if input.start_time <= current_block {
+            return Err(ContractError::invalid_input("Start time must be in the future"));
+        }
+        if input.min_raise > input.max_raise {
+            return Err(ContractError::invalid_input("Min raise must be <= max raise"));
+        }
+        if input.max_per_wallet == 0 {
+            return Err(ContractError::invalid_input("Max per wallet must be > 0"));
+        }
+
+        // Validate bonding curve if provided (before its cost is used below)
+        if let Some(ref curve) = input.bonding_curve {
+            if curve.step_size == 0 || input.total_tokens.div_ceil(curve.step_size) > MAX_CURVE_STEPS {
+                return Err(ContractError::invalid_input("Curve step size out of range"));
+            }
+            if matches!(curve.kind, CurveKind::Linear { increment: 0 } | CurveKind::Exponential { growth_bps: 0 }) {
+                return Err(ContractError::invalid_input("Curve must be increasing"));
+            }
+            if curve.target_reserve == 0 || curve.target_reserve > input.max_raise {
+                return Err(ContractError::invalid_input("Target reserve must be > 0 and within max raise"));
+            }
+            if input.min_raise > curve.target_reserve {
+                return Err(ContractError::invalid_input("Min raise exceeds target reserve"));
+            }
+            if input.auction.is_some() || input.pro_rata || !input.phases.is_empty() {
+                return Err(ContractError::invalid_input("Bonding curves use their own pricing"));
+            }
+        }
+
+        // Validate max_raise doesn't exceed what tokens can cover
+        let max_possible_raise = match input.bonding_curve {
+            Some(ref curve) => curve.cost(0, input.total_tokens),
+            None => input.total_tokens.saturating_mul(price_per_token),
+        };
+        if input.max_raise > max_possible_raise {
+            return Err(ContractError::invalid_input("Max raise exceeds token value"));
+        }
+
+        // Validate whitelist mode
+        if input.whitelist_enabled && input.whitelist_root.is_some() {
+            return Err(ContractError::invalid_input("Choose either an on-chain or a Merkle whitelist"));
+        }
+
+        // Validate auction pricing if provided
+        if let Some(ref auction) = input.auction {
+            if auction.floor_price == 0 || auction.floor_price > auction.start_price {
+                return Err(ContractError::invalid_input("Floor price must be > 0 and not above start price"));
+            }
+            if matches!(
+                auction.curve,
+                DecayCurve::Stepped { interval: 0 } | DecayCurve::Exponential { half_life: 0 }
+            ) {
+                return Err(ContractError::invalid_input("Decay interval must be > 0"));
+            }
+            if !input.phases.is_empty() {
+                return Err(ContractError::invalid_input("Auctions cannot be combined with sale phases"));
+            }
+            if input.pro_rata {
+                return Err(ContractError::invalid_input("Auctions cannot be oversubscribed"));
+            }
+        }
+
+        // Validate sale phases if provided
+        if !input.phases.is_empty() {
+            if input.phases.len() > MAX_PHASES {
+                return Err(ContractError::invalid_input("Too many sale phases"));
+            }
+            if input.whitelist_enabled || input.whitelist_root.is_some() {
+                return Err(ContractError::invalid_input("Phases define their own eligibility"));
+            }
+
+            let mut previous_end: Option<BlockNumber> = None;
+            for phase in &input.phases {
+                if phase.start_time > phase.end_time {
+                    return Err(ContractError::invalid_input("Phase start must not be after its end"));
+                }
+                if phase.start_time < input.start_time || phase.end_time > input.end_time {
+                    return Err(ContractError::invalid_input("Phase must be within the launch window"));
+                }
+                if previous_end.is_some_and(|end| phase.start_time <= end) {
+                    return Err(ContractError::invalid_input("Phases must be ordered and not overlap"));
+                }
+                if phase.price_per_token == 0 || phase.max_per_wallet == 0 {
+                    return Err(ContractError::invalid_input("Phase price and wallet cap must be > 0"));
+                }
+                if input.max_raise > input.total_tokens.saturating_mul(phase.price_per_token) {
+                    return Err(ContractError::invalid_input("Max raise exceeds token value"));
+                }
+                previous_end = Some(phase.end_time);
+            }
+        }
+
+        // Validate end-time extensions; phase and auction schedules are fixed to end_time
+        if let Some(ref anti_sniping) = input.anti_sniping {
+            if anti_sniping.window == 0 || anti_sniping.extension == 0 {
+                return Err(ContractError::invalid_input("Anti-sniping window and extension must be > 0"));
+            }
+            if anti_sniping.max_extension < anti_sniping.extension {
+                return Err(ContractError::invalid_input("Max extension must cover one extension"));
+            }
+        }
+        if (input.anti_sniping.is_some() || input.soft_cap_extension > 0)
+            && (input.auction.is_some() || !input.phases.is_empty())
+        {
+            return Err(ContractError::invalid_input("End-time extensions cannot be combined with auctions or phases"));
+        }
+        let latest_end_time = input.end_time
+            .saturating_add(input.anti_sniping.as_ref().map_or(0, |config| config.max_extension))
+            .saturating_add(input.soft_cap_extension);
+
+        // Validate payment currency
+        if input.payment_token == TokenType::Vft(ActorId::zero()) {
+            return Err(ContractError::ZeroAddress);
+        }
+
+        // Validate milestones if provided
+        if !input.milestones.is_empty() {
+            if input.milestones.len() > MAX_MILESTONES {
+                return Err(ContractError::invalid_input("Too many milestones"));
+            }
+            if input.milestones.iter().any(|m| m.amount == 0 || m.completed) {
+                return Err(ContractError::invalid_input("Milestones must be non-zero and incomplete"));
+            }
+            let total_bps: Amount = input.milestones.iter().map(|m| m.amount).sum();
+            if total_bps != MAX_BASIS_POINTS as Amount {
+                return Err(ContractError::invalid_input("Milestone tranches must sum to 10000 bps"));
+            }
+        }
+
+        // Validate governance configuration if provided
+        if let Some(ref governance) = input.governance {
+            if governance.quorum_bps == 0 || governance.quorum_bps > MAX_BASIS_POINTS {
+                return Err(ContractError::invalid_input("Quorum must be 1-10000 bps"));
+            }
+            if governance.approval_threshold_bps == 0 || governance.approval_threshold_bps > MAX_BASIS_POINTS {
+                return Err(ContractError::invalid_input("Approval threshold must be 1-10000 bps"));
+            }
+            if governance.voting_period == 0 {
+                return Err(ContractError::invalid_input("Voting period must be > 0"));
+            }
+        }
+
+        // Validate vesting configuration if provided
+        if let Some(ref vesting) = input.vesting_config {
+            vesting.validate()?;
+            if vesting.vesting_end() < latest_end_time {
+                return Err(ContractError::invalid_input("Vesting must end after launch ends"));
+            }
+        }
+
+        // Validate team allocation if provided
+        if let Some(ref team) = input.team_allocation {
+            if team.total == 0 {
+                return Err(ContractError::invalid_input("Team allocation must be positive"));
+            }
+            if team.beneficiaries.is_empty() || team.beneficiaries.len() > MAX_TEAM_BENEFICIARIES {
+                return Err(ContractError::invalid_input("Team allocation needs 1-20 beneficiaries"));
+            }
+            team.vesting.validate()?;
+            if team.vesting.cliff_duration > team.vesting.vesting_duration {
+                return Err(ContractError::invalid_input("Team cliff must not exceed vesting duration"));
+            }
+            let mut beneficiaries = BTreeSet::new();
+            let mut total_bps: u32 = 0;
+            for share in &team.beneficiaries {
+                if share.beneficiary == ActorId::default() || !beneficiaries.insert(share.beneficiary) {
+                    return Err(ContractError::invalid_input("Team beneficiaries must be unique and non-zero"));
+                }
+                if share.share_bps == 0 {
+                    return Err(ContractError::invalid_input("Team shares must be positive"));
+                }
+                total_bps = total_bps.saturating_add(share.share_bps as u32);
+            }
+            if total_bps != MAX_BASIS_POINTS as u32 {
+                return Err(ContractError::invalid_input("Team shares must sum to 10000 bps"));
+            }
+        }
+        let team_total = input.team_allocation.as_ref().map_or(0, |team| team.total);
+
+        // Reserve enough tokens to pair the liquidity share at the lowest sale price
+        let liquidity_reserve = match input.liquidity {
+            Some(ref liquidity) => {
+                if liquidity.raise_bps == 0 || liquidity.raise_bps > MAX_BASIS_POINTS {
+                    return Err(ContractError::invalid_input("Liquidity share must be 1-10000 bps"));
+                }
+                if liquidity.lock_duration < self.storage().min_liquidity_lock {
+                    return Err(ContractError::invalid_input("Liquidity lock duration below platform minimum"));
+                }
+                let lowest_price = match (&input.auction, &input.bonding_curve) {
+                    (Some(auction), _) => auction.floor_price,
+                    (None, Some(curve)) => curve.initial_price,
+                    (None, None) => input.phases
+                        .iter()
+                        .map(|phase| phase.price_per_token)
+                        .min()
+                        .unwrap_or(price_per_token),
+                };
+                input.max_raise
+                    .saturating_mul(liquidity.raise_bps as Amount)
+                    .checked_div(MAX_BASIS_POINTS as Amount)
+                    .unwrap_or(0)
+                    .checked_div(lowest_price)
+                    .unwrap_or(0)
+            }
+            None => 0,
+        };
+
+        let (token_address, deployed) = match input.token_address {
+            // Creator-supplied token, deposited later via deposit_tokens
+            Some(token_address) => (token_address, false),
+            None => {
+                // Get deployment configuration (storage is not borrowed across the await)
+                let (vft_code_id, gas_for_program, gas_for_reply) = {
+                    let s = self.storage();
+                    (s.vft_code_id, s.gas_for_program, s.gas_for_reply)
+                };
+
+                // Check if VFT code ID is set
+                if vft_code_id == CodeId::default() {
+                    return Err(ContractError::invalid_state("VFT code ID not set"));
+                }
+
+                // Deploy the token contract with configurable gas
+                let token_address = VftFactory::deploy_token(
+                    input.token_name.clone(),
+                    input.token_symbol.clone(),
+                    U256::from(input.total_tokens.saturating_add(liquidity_reserve).saturating_add(team_total)),
+                    vft_code_id,
+                    gas_for_program,
+                    gas_for_reply,
+                ).await?;
+
+                (token_address, true)
+            }
+        };
+
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        let launch_id = s.next_launch_id;
+        s.next_launch_id = s.next_launch_id
+            .checked_add(1)
+            .ok_or(ContractError::Overflow)?;
+
+        let launch = Launch {
+            id: launch_id,
+            creator,
+            title: input.title.clone(),
+            description: input.description,
+            token_address,
+            total_tokens: input.total_tokens,
+            tokens_remaining: input.total_tokens,
+            unsold_returned: 0,
+            price_per_token,
+            payment_token: input.payment_token,
+            min_raise: input.min_raise,
+            max_raise: input.max_raise,
+            total_raised: 0,
+            max_per_wallet: input.max_per_wallet,
+            start_time: input.start_time,
+            end_time: input.end_time,
+            anti_sniping: input.anti_sniping,
+            anti_sniping_extended: 0,
+            soft_cap_extension: input.soft_cap_extension,
+            soft_cap_extended: false,
+            automated: false,
+            whitelist: BTreeSet::new(),
+            whitelist_enabled: input.whitelist_enabled,
+            whitelist_root: input.whitelist_root,
+            phases: input.phases,
+            phase_contributions: BTreeMap::new(),
+            auction: input.auction,
+            clearing_price: None,
+            pro_rata: input.pro_rata,
+            bonding_curve: input.bonding_curve,
+            liquidity: input.liquidity,
+            liquidity_reserve,
+            seeded_liquidity: None,
+            excess_refunds: BTreeMap::new(),
+            contributions: BTreeMap::new(),
+            tokens_purchased: BTreeMap::new(),
+            claimed: BTreeMap::new(),
+            vesting_config: input.vesting_config,
+            positions_transferable: input.positions_transferable,
+            team_tokens: input.team_allocation.as_ref().map(TeamAllocation::allocations).unwrap_or_default(),
+            team_allocation: input.team_allocation,
+            team_claimed: BTreeMap::new(),
+            team_revocations: BTreeMap::new(),
+            milestones: input.milestones,
+            milestone_approvals: BTreeMap::new(),
+            governance: input.governance,
+            status: LaunchStatus::Pending,
+            created_at: current_block,
+            // Deployed tokens are minted directly to contract
+            tokens_deposited: deployed,
+            funds_withdrawn: false,
+            funds_released: 0,
+            refunds_processed: false,
+            contributors: Vec::new(),
+            distribution_cursor: 0,
+            refund_cursor: 0,
+            finalization_deadline: input.end_time.saturating_add(FINALIZATION_GRACE_PERIOD),
+        };
+
+        s.launches.insert(launch_id, launch);
+
+        // Emit TokenDeployed event for the newly created token
+        if deployed {
+            let _ = self.emit_event(LaunchpadEvent::TokenDeployed {
+                launch_id,
+                token_address,
+                name: input.token_name,
+                symbol: input.token_symbol,
+                total_supply: input.total_tokens.saturating_add(liquidity_reserve).saturating_add(team_total),
+            });
+        }
+
+        let _ = self.emit_event(LaunchpadEvent::LaunchCreated {
+            launch_id,
+            creator,
+            title: input.title,
+            token_address,
+            total_tokens: input.total_tokens,
+            price_per_token,
+            payment_token: input.payment_token,
+            min_raise: input.min_raise,
+            max_raise: input.max_raise,
+            start_time: input.start_time,
+            end_time: input.end_time,
+        });
+
+        Ok(launch_id)
+    }
+
+    /// Add addresses to whitelist.
+    #[export(unwrap_result)]
+    pub fn add_to_whitelist(
+        &mut self,
+        launch_id: Id,
+        addresses: Vec<ActorId>,
+    ) -> Result<(), ContractError> {
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+        let caller = gstd::msg::source();
+
+        let launch = s.launches.get_mut(&launch_id)
+            .ok_or(ContractError::NotFound)?;
+
+        if caller != launch.creator {
+            return Err(ContractError::Unauthorized);
+        }
+
+        if launch.whitelist_root.is_some() {
+            return Err(ContractError::invalid_state("Launch uses a Merkle whitelist"));
+        }
+
+        // Can only modify whitelist before launch starts (Pending status only)
+        if launch.status != LaunchStatus::Pending {
+            return Err(ContractError::invalid_state("Cannot modify whitelist after launch starts"));
+        }
+
+        let count = addresses.len() as u32;
+        for addr in addresses {
+            launch.whitelist.insert(addr);
+        }
+
+        let _ = self.emit_event(LaunchpadEvent::WhitelistUpdated {
+            launch_id,
+            addresses_added: count,
+        });
+
+        Ok(())
+    }
+
+    /// Deposit the sale tokens of a bring-your-own-token launch (creator only).
+    ///
+    /// The creator must first approve the launchpad for `total_tokens`. Only
+    /// the balance the transfer adds counts; if it falls short, nothing is
+    /// recorded, `TokenTransferFailed` is emitted and 0 is returned.
+    #[export(unwrap_result)]
+    pub async fn deposit_tokens(&mut self, launch_id: Id) -> Result<Amount, ContractError> {
+        let _guard = ReentrancyGuard::start(self.storage)?;
+        let caller = gstd::msg::source();
+
+        // Validate; storage is released before the await
+        let (token_address, total_tokens) = {
+            let s = self.storage();
+
+            let launch = s.launches.get(&launch_id)
+                .ok_or(ContractError::NotFound)?;
+
+            if caller != launch.creator {
+                return Err(ContractError::Unauthorized);
+            }
+
+            if launch.status != LaunchStatus::Pending {
+                return Err(ContractError::invalid_state("Launch must be in Pending state"));
+            }
+
+            if launch.tokens_deposited {
+                return Err(ContractError::AlreadyProcessed);
+            }
+
+            (launch.token_address, launch.token_supply())
+        };
+
+        if !verify_token_approval(token_address, caller, U256::from(total_tokens)).await? {
+            return Err(ContractError::invalid_state("Insufficient token approval"));
+        }
+
+        // Count only what this transfer adds, not tokens already held
+        let balance_before = launchpad_token_balance(token_address).await?;
+
+        let deposited = match VftClient::transfer_from(
+            token_address,
+            caller,
+            gstd::exec::program_id(),
+            U256::from(total_tokens),
+        ).await {
+            Ok(()) => launchpad_token_balance(token_address)
+                .await
+                .map_or(0, |balance_after| balance_after.saturating_sub(balance_before)),
+            Err(_) => 0,
+        };
+
+        if deposited < total_tokens {
+            // Leave the launch undeposited (returning Err would discard this event)
+            let _ = self.emit_event(LaunchpadEvent::TokenTransferFailed {
+                launch_id,
+                user: caller,
+                amount: total_tokens,
+                reason: String::from("Token deposit not confirmed"),
+            });
+            return Ok(0);
+        }
+
+        if let Some(launch) = self.storage_mut().launches.get_mut(&launch_id) {
+            launch.tokens_deposited = true;
+        }
+
+        let _ = self.emit_event(LaunchpadEvent::TokensDeposited {
+            launch_id,
+            amount: total_tokens,
+        });
+
+        Ok(total_tokens)
+    }
+
+    /// Start the launch (creator only).
+    ///
+    /// Anyone may start an automated launch once `start_time` has passed, in
+    /// case its scheduled activation did not run.
+    #[export(unwrap_result)]
+    pub fn start_launch(&mut self, launch_id: Id) -> Result<(), ContractError> {
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+        let caller = gstd::msg::source();
+        let current_block = gstd::exec::block_height();
+
+        let launch = s.launches.get_mut(&launch_id)
+            .ok_or(ContractError::NotFound)?;
+
+        let overdue = launch.automated && current_block >= launch.start_time;
+        if caller != launch.creator && !overdue {
+            return Err(ContractError::Unauthorized);
+        }
+
+        if launch.status != LaunchStatus::Pending {
+            return Err(ContractError::invalid_state("Launch must be in Pending state"));
+        }
+
+        // Sale tokens must be held by the contract before activation
+        if !launch.tokens_deposited {
+            return Err(ContractError::invalid_state("Tokens not deposited"));
+        }
+
+        launch.status = LaunchStatus::Active;
+
+        let _ = self.emit_event(LaunchpadEvent::LaunchStarted { launch_id });
+
+        Ok(())
+    }
+
+
+    // -------------------------------------------------------------------------
+    // CONTRIBUTIONS
+    // -------------------------------------------------------------------------
+
+    /// Contribute to a native-VARA launch.
+    ///
+    /// A rejected contribution fails the message before anything is sent, so
+    /// the attached value goes back with the error reply. Only the unused part
+    /// of an accepted contribution is refunded explicitly.
+    #[export(unwrap_result)]
+    pub fn contribute(&mut self, launch_id: Id) -> Result<Amount, ContractError> {
+        self.contribute_native(launch_id, None)
+    }
+
+    /// Contribute to a native-VARA launch with a Merkle whitelist proof.
+    #[export(unwrap_result)]
+    pub fn contribute_with_proof(&mut self, launch_id: Id, proof: MerkleProof) -> Result<Amount, ContractError> {
+        self.contribute_native(launch_id, Some(&proof))
+    }
+
+    fn contribute_native(&mut self, launch_id: Id, proof: Option<&MerkleProof>) -> Result<Amount, ContractError> {
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        if s.paused {
+            return Err(ContractError::invalid_state("Contract is paused"));
+        }
+
+        let contributor = gstd::msg::source();
+        let value = gstd::msg::value() as Amount;
+        let current_block = gstd::exec::block_height();
+
+        let launch = s.launches.get_mut(&launch_id)
+            .ok_or(ContractError::NotFound)?;
+
+        // Currency check - VFT launches go through contribute_vft
+        if launch.payment_token != TokenType::Native {
+            return Err(ContractError::invalid_state("Launch is VFT-denominated, use contribute_vft"));
+        }
+
+        let quote = launch.quote_contribution(&contributor, value, current_block, proof)?;
+        let refund = value.saturating_sub(quote.amount);
+
+        // Update state
+        launch.record_contribution(contributor, &quote);
+
+        let _ = self.emit_event(LaunchpadEvent::Contributed {
+            launch_id,
+            contributor,
+            amount: quote.amount,
+            tokens_purchased: quote.tokens,
+            price_per_token: quote.price,
+            refunded: refund,
+        });
+
+        if let Some(old_end_time) = launch.extend_for_late_contribution(current_block) {
+            let _ = self.emit_event(LaunchpadEvent::AntiSnipingExtended {
+                launch_id,
+                old_end_time,
+                new_end_time: launch.end_time,
+            });
+        }
+
+        // Check if fully subscribed now
+        if launch.is_fully_subscribed() {
+            let _ = self.emit_event(LaunchpadEvent::SaleFullySubscribed {
+                launch_id,
+                total_raised: launch.total_raised,
+            });
+        }
+        if launch.is_graduated() {
+            let _ = self.emit_event(LaunchpadEvent::LaunchGraduated {
+                launch_id,
+                reserve: launch.total_raised,
+                tokens_sold: launch.tokens_sold(),
+            });
+        }
+
+        // Refund excess
+        s.send_native(contributor, contributor, refund)?;
+
+        Ok(quote.tokens)
+    }
+
+    /// Contribute `amount` of the payment token to a VFT-denominated launch.
+    ///
+    /// The contribution is capped to the remaining allocation and pulled with
+    /// `transfer_from`, so the caller must approve the launchpad beforehand.
+    #[export(unwrap_result)]
+    pub async fn contribute_vft(&mut self, launch_id: Id, amount: Amount) -> Result<Amount, ContractError> {
+        self.contribute_token(launch_id, amount, None).await
+    }
+
+    /// Contribute to a VFT-denominated launch with a Merkle whitelist proof.
+    #[export(unwrap_result)]
+    pub async fn contribute_vft_with_proof(
+        &mut self,
+        launch_id: Id,
+        amount: Amount,
+        proof: MerkleProof,
+    ) -> Result<Amount, ContractError> {
+        self.contribute_token(launch_id, amount, Some(&proof)).await
+    }
+
+    async fn contribute_token(
+        &mut self,
+        launch_id: Id,
+        amount: Amount,
+        proof: Option<&MerkleProof>,
+    ) -> Result<Amount, ContractError> {
+        let contributor = gstd::msg::source();
+        let current_block = gstd::exec::block_height();
+
+        if gstd::msg::value() > 0 {
+            return Err(ContractError::invalid_input("Native value not accepted for VFT contributions"));
+        }
+
+        // Validate and reserve the allocation; storage is released before the await
+        let (payment_token, quote) = {
+            let mut storage = self.storage_mut();
+            let s = &mut *storage;
+
+            if s.paused {
+                return Err(ContractError::invalid_state("Contract is paused"));
+            }
+
+            let launch = s.launches.get_mut(&launch_id)
+                .ok_or(ContractError::NotFound)?;
+
+            let TokenType::Vft(payment_token) = launch.payment_token else {
+                return Err(ContractError::invalid_state("Launch is VARA-denominated, use contribute"));
+            };
+
+            let quote = launch.quote_contribution(&contributor, amount, current_block, proof)?;
+
+            // Update state BEFORE async transfer (CEI pattern)
+            launch.record_contribution(contributor, &quote);
+            (payment_token, quote)
+        };
+
+        // Pull the contribution from the contributor
+        let transfer_result = VftClient::transfer_from(
+            payment_token,
+            contributor,
+            gstd::exec::program_id(),
+            U256::from(quote.amount),
+        ).await;
+
+        let mut storage = self.storage_mut();
+        let launch = storage.launches.get_mut(&launch_id)
+            .ok_or(ContractError::NotFound)?;
+
+        if transfer_result.is_err() {
+            // Rollback state on transfer failure. State written before the await is
+            // already committed, so this must not return Err: the panic from
+            // `unwrap_result` would discard the rollback along with it.
+            launch.revert_contribution(contributor, &quote);
+
+            let _ = self.emit_event(LaunchpadEvent::TokenTransferFailed {
+                launch_id,
+                user: contributor,
+                amount: quote.amount,
+                reason: String::from("VFT transfer_from failed"),
+            });
+
+            return Ok(0);
+        }
+
+        let _ = self.emit_event(LaunchpadEvent::Contributed {
+            launch_id,
+            contributor,
+            amount: quote.amount,
+            tokens_purchased: quote.tokens,
+            price_per_token: quote.price,
+            refunded: 0,
+        });
+
+        // Extend only once the payment is in
+        if let Some(old_end_time) = launch.extend_for_late_contribution(current_block) {
+            let _ = self.emit_event(LaunchpadEvent::AntiSnipingExtended {
+                launch_id,
+                old_end_time,
+                new_end_time: launch.end_time,
+            });
+        }
+
+        // Check if fully subscribed now
+        if launch.is_fully_subscribed() {
+            let _ = self.emit_event(LaunchpadEvent::SaleFullySubscribed {
+                launch_id,
+                total_raised: launch.total_raised,
+            });
+        }
+        if launch.is_graduated() {
+            let _ = self.emit_event(LaunchpadEvent::LaunchGraduated {
+                launch_id,
+                reserve: launch.total_raised,
+                tokens_sold: launch.tokens_sold(),
+            });
+        }
+
+        Ok(quote.tokens)
+    }
+
+    /// Sell bonding-curve tokens back into the reserve before graduation.
+    ///
+    /// Tokens are priced along the curve, so the seller receives what the
+    /// last `tokens` sold would cost to buy now.
+    #[export(unwrap_result)]
+    pub async fn sell_tokens(&mut self, launch_id: Id, tokens: Amount) -> Result<Amount, ContractError> {
+        let _guard = ReentrancyGuard::start(self.storage)?;
+
+        let seller = gstd::msg::source();
+        let current_block = gstd::exec::block_height();
+
+        if tokens == 0 {
+            return Err(ContractError::ZeroAmount);
+        }
+
+        // Validate and release the position; storage is released before the await
+        let (payment_token, proceeds, released) = {
+            let mut storage = self.storage_mut();
+            let s = &mut *storage;
+
+            if s.paused {
+                return Err(ContractError::invalid_state("Contract is paused"));
+            }
+
+            let launch = s.launches.get_mut(&launch_id)
+                .ok_or(ContractError::NotFound)?;
+
+            let Some(ref curve) = launch.bonding_curve else {
+                return Err(ContractError::invalid_state("Launch has no bonding curve"));
+            };
+
+            if launch.status != LaunchStatus::Active || !launch.is_in_time_window(current_block) {
+                return Err(ContractError::invalid_state("Launch is not active"));
+            }
+            if launch.is_graduated() {
+                return Err(ContractError::invalid_state("Launch has graduated"));
+            }
+
+            let held = launch.tokens_purchased.get(&seller).copied().unwrap_or(0);
+            if tokens > held {
+                return Err(ContractError::InsufficientFunds);
+            }
+
+            let proceeds = curve.cost(launch.tokens_sold() - tokens, tokens);
+
+            // Update state BEFORE async transfer (CEI pattern)
+            let released = launch.record_sale(seller, tokens, proceeds);
+            (launch.payment_token, proceeds, released)
+        };
+
+        let transfer_result = self.transfer_payment(launch_id, payment_token, seller, seller, proceeds).await;
+
+        let mut storage = self.storage_mut();
+        let launch = storage.launches.get_mut(&launch_id)
+            .ok_or(ContractError::NotFound)?;
+
+        if transfer_result.is_err() {
+            // Give the position back (returning Err would discard this rollback)
+            launch.revert_sale(seller, tokens, proceeds, released);
+
+            let _ = self.emit_event(LaunchpadEvent::TokenTransferFailed {
+                launch_id,
+                user: seller,
+                amount: proceeds,
+                reason: String::from("Sell-back transfer failed"),
+            });
+
+            return Ok(0);
+        }
+
+        let _ = self.emit_event(LaunchpadEvent::TokensSold {
+            launch_id,
+            seller,
+            tokens,
+            proceeds,
+        });
+
+        Ok(proceeds)
+    }
+
+    // -------------------------------------------------------------------------
+    // FINALIZATION
+    // -------------------------------------------------------------------------
+
+    /// Extend a sale that has not reached `min_raise` by its
+    /// `soft_cap_extension` (creator only, once).
+    ///
+    /// Allowed until `soft_cap_extension` blocks after `end_time`; the sale
+    /// then runs until the old end time plus the extension.
+    #[export(unwrap_result)]
+    pub fn extend_for_soft_cap(&mut self, launch_id: Id) -> Result<BlockNumber, ContractError> {
+        let mut storage = self.storage_mut();
+        let caller = gstd::msg::source();
+        let current_block = gstd::exec::block_height();
+
+        let launch = storage.launches.get_mut(&launch_id)
+            .ok_or(ContractError::NotFound)?;
+
+        if caller != launch.creator {
+            return Err(ContractError::Unauthorized);
+        }
+        if !launch.can_extend_for_soft_cap(current_block) {
+            return Err(ContractError::invalid_state("Soft-cap extension not available"));
+        }
+
+        launch.soft_cap_extended = true;
+        let old_end_time = launch.extend_end_time(launch.soft_cap_extension);
+
+        let _ = self.emit_event(LaunchpadEvent::SoftCapExtended {
+            launch_id,
+            old_end_time,
+            new_end_time: launch.end_time,
+        });
+
+        Ok(launch.end_time)
+    }
+
+    /// Finalize launch after end time (anyone can call).
+    ///
+    /// A successful launch with a liquidity share is seeded into the DEX right
+    /// away; if that fails, `seed_liquidity` can retry it.
+    #[export(unwrap_result)]
+    pub async fn finalize(&mut self, launch_id: Id) -> Result<(), ContractError> {
+        if self.finalize_sale(launch_id)? {
+            if let Err(err) = self.seed_launch_liquidity(launch_id).await {
+                let _ = self.emit_event(LaunchpadEvent::LiquiditySeedingFailed {
+                    launch_id,
+                    reason: error_reason(err),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Close the sale and move it to distribution or refunds.
+    ///
+    /// Returns whether liquidity should be seeded.
+    fn finalize_sale(&mut self, launch_id: Id) -> Result<bool, ContractError> {
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+        let current_block = gstd::exec::block_height();
+
+        let launch = s.launches.get_mut(&launch_id)
+            .ok_or(ContractError::NotFound)?;
+
+        // Can only finalize active launches
+        if launch.status != LaunchStatus::Active {
+            return Err(ContractError::invalid_state("Launch must be Active to finalize"));
+        }
+
+        // Check if end time passed or fully subscribed
+        if current_block <= launch.end_time && !launch.is_fully_subscribed() {
+            return Err(ContractError::invalid_state("Launch has not ended yet"));
+        }
+
+        // Give the creator a chance to extend before failing the sale
+        if launch.can_extend_for_soft_cap(current_block) {
+            return Err(ContractError::invalid_state("Creator may still extend the sale"));
+        }
+
+        // Determine outcome
+        let reason = if launch.is_fully_subscribed() {
+            "Fully subscribed"
+        } else {
+            "Time expired"
+        };
+
+        // Emit sale ended
+        let _ = self.emit_event(LaunchpadEvent::SaleEnded {
+            launch_id,
+            total_raised: launch.total_raised,
+            total_contributors: launch.contributors.len() as u32,
+            reason: String::from(reason),
+        });
+
+        launch.status = LaunchStatus::Ended;
+
+        // Settle an auction at its clearing price; the soft cap applies to the settled raise
+        let settlement = launch.auction.as_ref().map(|auction| {
+            let clearing_price = launch.clearing_price
+                .unwrap_or_else(|| auction.price_at(launch.start_time, launch.end_time, current_block));
+            let sold = launch.total_tokens.saturating_sub(launch.tokens_remaining);
+            (clearing_price, sold.saturating_mul(clearing_price).min(launch.total_raised))
+        });
+        let min_raise_met = match settlement {
+            Some((_, settled_raise)) => settled_raise >= launch.min_raise,
+            None => launch.min_raise_met(),
+        };
+
+        // Determine success or failure
+        if min_raise_met {
+            if let Some((clearing_price, _)) = settlement {
+                launch.settle_auction(clearing_price);
+
+                let _ = self.emit_event(LaunchpadEvent::AuctionSettled {
+                    launch_id,
+                    clearing_price,
+                    total_raised: launch.total_raised,
+                    total_excess: launch.excess_refunds.values().sum(),
+                });
+            }
+
+            if launch.pro_rata {
+                let total_committed = launch.total_raised;
+                launch.settle_pro_rata();
+
+                let _ = self.emit_event(LaunchpadEvent::AllocationsSettled {
+                    launch_id,
+                    total_committed,
+                    total_raised: launch.total_raised,
+                    total_excess: launch.excess_refunds.values().sum(),
+                });
+            }
+
+            launch.status = LaunchStatus::Succeeded;
+
+            let _ = self.emit_event(LaunchpadEvent::LaunchSucceeded {
+                launch_id,
+                total_raised: launch.total_raised,
+            });
+
+            // Move to distribution pending
+            launch.status = LaunchStatus::DistributionPending;
+
+            let _ = self.emit_event(LaunchpadEvent::DistributionPending { launch_id });
+        } else {
+            launch.status = LaunchStatus::Failed;
+
+            let _ = self.emit_event(LaunchpadEvent::LaunchFailed {
+                launch_id,
+                total_raised: launch.total_raised,
+                min_raise: launch.min_raise,
+            });
+
+            // Move to refund available
+            launch.status = LaunchStatus::RefundAvailable;
+
+            let _ = self.emit_event(LaunchpadEvent::RefundsAvailable {
+                launch_id,
+                total_to_refund: launch.total_raised,
+                num_contributors: launch.contributors.len() as u32,
+            });
+        }
+
+        Ok(launch.is_successful() && launch.liquidity.is_some())
+    }
+
+    /// Seed a successful launch's liquidity share into the DEX (anyone can call).
+    ///
+    /// Retries seeding that failed during `finalize`, e.g. before a DEX was set.
+    #[export(unwrap_result)]
+    pub async fn seed_liquidity(&mut self, launch_id: Id) -> Result<Amount, ContractError> {
+        self.seed_launch_liquidity(launch_id).await
+    }
+
+    /// Give up on seeding a launch's liquidity once the seeding period after
+    /// `end_time` has passed (anyone can call).
+    ///
+    /// The liquidity share joins the creator's funds, still subject to
+    /// milestones, and the whole reserve comes back with `return_unsold_tokens`.
+    /// Returns the released share of the raise.
+    #[export(unwrap_result)]
+    pub fn release_unseeded_liquidity(&mut self, launch_id: Id) -> Result<Amount, ContractError> {
+        // Not while a seeding attempt is awaiting the DEX
+        let _guard = ReentrancyGuard::start(self.storage)?;
+        let current_block = gstd::exec::block_height();
+
+        let (funds, tokens) = {
+            let mut storage = self.storage_mut();
+            let s = &mut *storage;
+            let seeding_period = s.liquidity_seeding_period;
+
+            let launch = s.launches.get_mut(&launch_id)
+                .ok_or(ContractError::NotFound)?;
+
+            if !launch.is_successful() {
+                return Err(ContractError::invalid_state("Launch not successful"));
+            }
+            if launch.liquidity.is_none() {
+                return Err(ContractError::invalid_state("Launch has no liquidity share"));
+            }
+            if launch.seeded_liquidity.is_some() {
+                return Err(ContractError::AlreadyProcessed);
+            }
+            if current_block < launch.end_time.saturating_add(seeding_period) {
+                return Err(ContractError::invalid_state("Liquidity seeding period not over"));
+            }
+
+            let funds = launch.liquidity_funds();
+            launch.liquidity = None;
+            launch.funds_withdrawn = launch.funds_released >= launch.creator_funds();
+
+            (funds, launch.liquidity_reserve)
+        };
+
+        let _ = self.emit_event(LaunchpadEvent::LiquidityReleased { launch_id, funds, tokens });
+
+        Ok(funds)
+    }
+
+    /// Pair the launch's liquidity share with tokens at the sale price and add
+    /// both to the DEX. The pool shares are locked in the launchpad for the
+    /// launch's `lock_duration`.
+    ///
+    /// Validation errors are returned before anything is sent. Once messages
+    /// are out, failures emit `LiquiditySeedingFailed` and return `Ok(0)`.
+    async fn seed_launch_liquidity(&mut self, launch_id: Id) -> Result<Amount, ContractError> {
+        let _guard = ReentrancyGuard::start(self.storage)?;
+        let current_block = gstd::exec::block_height();
+
+        // Validate; storage is released before the await
+        let (dex, token_address, payment_token, funds, tokens, lock_duration) = {
+            let s = self.storage();
+
+            let launch = s.launches.get(&launch_id)
+                .ok_or(ContractError::NotFound)?;
+
+            if !launch.is_successful() {
+                return Err(ContractError::invalid_state("Launch not successful"));
+            }
+            if launch.liquidity.is_none() {
+                return Err(ContractError::invalid_state("Launch has no liquidity share"));
+            }
+            if launch.seeded_liquidity.is_some() {
+                return Err(ContractError::AlreadyProcessed);
+            }
+
+            let dex = s.dex.ok_or(ContractError::invalid_state("DEX not configured"))?;
+            let lock_duration = launch.liquidity.as_ref().map_or(0, |config| config.lock_duration);
+
+            let funds = launch.liquidity_funds();
+            let tokens = funds
+                .checked_div(launch.price_at(current_block))
+                .unwrap_or(0)
+                .min(launch.liquidity_reserve);
+            if funds == 0 || tokens == 0 {
+                return Err(ContractError::ZeroAmount);
+            }
+
+            (dex, launch.token_address, launch.payment_token, funds, tokens, lock_duration)
+        };
+
+        let quote = match payment_token {
+            TokenType::Native => None,
+            TokenType::Vft(quote_token) => Some(quote_token),
+        };
+
+        // Let the DEX pull both sides, then add them to the pool
+        let mut approval = VftClient::approve(token_address, dex, U256::from(tokens)).await;
+        if let (Ok(()), Some(quote_token)) = (&approval, quote) {
+            approval = VftClient::approve(quote_token, dex, U256::from(funds)).await;
+        }
+        let result = match approval {
+            Ok(()) => {
+                let launchpad = gstd::exec::program_id();
+                DexClient::add_liquidity(dex, token_address, quote, tokens, funds, launchpad).await
+            }
+            Err(err) => Err(err),
+        };
+
+        let Ok(liquidity) = result else {
+            let _ = self.emit_event(LaunchpadEvent::LiquiditySeedingFailed {
+                launch_id,
+                reason: String::from("DEX rejected the liquidity"),
+            });
+            return Ok(0);
+        };
+
+        let unlock_at = current_block.saturating_add(lock_duration);
+        {
+            let mut storage = self.storage_mut();
+            let s = &mut *storage;
+
+            if let Some(launch) = s.launches.get_mut(&launch_id) {
+                launch.seeded_liquidity = Some(SeededLiquidity {
+                    dex,
+                    funds,
+                    tokens,
+                    liquidity,
+                    seeded_at: current_block,
+                });
+            }
+
+            s.liquidity_locks.insert(launch_id, LiquidityLock {
+                dex,
+                token: token_address,
+                quote,
+                liquidity,
+                locked_at: current_block,
+                unlock_at,
+                withdrawn: false,
+            });
+        }
+
+        let _ = self.emit_event(LaunchpadEvent::LiquiditySeeded {
+            launch_id,
+            dex,
+            funds,
+            tokens,
+            liquidity,
+            unlock_at,
+        });
+
+        Ok(liquidity)
+    }
+
+    /// Withdraw a launch's locked pool shares once the lock expires (creator only).
+    #[export(unwrap_result)]
+    pub async fn withdraw_locked_liquidity(&mut self, launch_id: Id) -> Result<Amount, ContractError> {
+        let _guard = ReentrancyGuard::start(self.storage)?;
+        let caller = gstd::msg::source();
+        let current_block = gstd::exec::block_height();
+
+        // Validate and mark as withdrawn; storage is released before the await
+        let lock = {
+            let mut storage = self.storage_mut();
+            let s = &mut *storage;
+
+            let launch = s.launches.get(&launch_id)
+                .ok_or(ContractError::NotFound)?;
+
+            if caller != launch.creator {
+                return Err(ContractError::Unauthorized);
+            }
+
+            let lock = s.liquidity_locks.get_mut(&launch_id)
+                .ok_or(ContractError::invalid_state("No locked liquidity"))?;
+
+            if lock.withdrawn {
+                return Err(ContractError::AlreadyProcessed);
+            }
+            if !lock.is_unlocked(current_block) {
+                return Err(ContractError::invalid_state("Liquidity still locked"));
+            }
+
+            lock.withdrawn = true;
+            lock.clone()
+        };
+
+        let result = DexClient::transfer_liquidity(lock.dex, lock.token, lock.quote, caller, lock.liquidity).await;
+
+        if result.is_err() {
+            // Keep the lock withdrawable (returning Err would discard this, see contribute_vft)
+            if let Some(lock) = self.storage_mut().liquidity_locks.get_mut(&launch_id) {
+                lock.withdrawn = false;
+            }
+
+            let _ = self.emit_event(LaunchpadEvent::TokenTransferFailed {
+                launch_id,
+                user: caller,
+                amount: lock.liquidity,
+                reason: String::from("Liquidity transfer failed"),
+            });
+
+            return Ok(0);
         }
+
+        let _ = self.emit_event(LaunchpadEvent::LiquidityWithdrawn {
+            launch_id,
+            creator: caller,
+            liquidity: lock.liquidity,
+        });
+
+        Ok(lock.liquidity)
     }
-}
 
-// =============================================================================
-// CONTRACT METADATA
-// =============================================================================
+    /// Cancel launch (creator before it starts, or an admin any time).
+    #[export(unwrap_result)]
+    pub fn cancel_launch(&mut self, launch_id: Id) -> Result<(), ContractError> {
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+        let caller = gstd::msg::source();
+        let is_admin = s.has_role(RoleId::DEFAULT_ADMIN, &caller);
 
-/// Contract name and version.
-pub const CONTRACT_NAME: &str = "Vara Token Launchpad";
-pub const CONTRACT_VERSION: &str = "1.0.0";
+        let launch = s.launches.get_mut(&launch_id)
+            .ok_or(ContractError::NotFound)?;
 
-/// Finalization grace period in blocks (approximately 30 days at 1 block/sec).
-/// After end_time + FINALIZATION_GRACE_PERIOD, admin can force refunds for stuck launches.
-pub const FINALIZATION_GRACE_PERIOD: BlockNumber = 2_592_000;
+        // Idempotency check - prevent re-cancellation of already processed launches
+        if matches!(launch.status, LaunchStatus::Cancelled | LaunchStatus::RefundAvailable | LaunchStatus::Finalized) {
+            return Err(ContractError::AlreadyProcessed);
+        }
 
-// =============================================================================
-// SERVICE IMPLEMENTATION
-// =============================================================================
+        // Authorization check
+        let is_creator = caller == launch.creator;
 
-/// Launchpad Service implementation.
-pub struct LaunchpadService(());
+        if !is_creator && !is_admin {
+            return Err(ContractError::Unauthorized);
+        }
 
-impl LaunchpadService {
-    pub fn new() -> Self {
-        Self(())
+        // Creator can only cancel in Pending state (before contributions)
+        // Admin can cancel any time (emergency)
+        if !is_admin && launch.status != LaunchStatus::Pending {
+            return Err(ContractError::invalid_state("Creator can only cancel pending launches"));
+        }
+
+        self.apply_cancellation(launch, caller);
+
+        Ok(())
+    }
+
+    /// Cancel `launch`, opening refunds if anything was raised.
+    fn apply_cancellation(&mut self, launch: &mut Launch, by: ActorId) {
+        let launch_id = launch.id;
+        launch.status = LaunchStatus::Cancelled;
+
+        let _ = self.emit_event(LaunchpadEvent::LaunchCancelled { launch_id, by });
+
+        // If there were contributions, enable refunds
+        if launch.total_raised > 0 {
+            launch.status = LaunchStatus::RefundAvailable;
+
+            let _ = self.emit_event(LaunchpadEvent::RefundsAvailable {
+                launch_id,
+                total_to_refund: launch.total_raised,
+                num_contributors: launch.contributors.len() as u32,
+            });
+        } else {
+            // Nothing to refund
+            launch.refunds_processed = true;
+            launch.status = LaunchStatus::Finalized;
+            let _ = self.emit_event(LaunchpadEvent::LaunchFinalized { launch_id });
+        }
     }
-}
 
-#[sails_rs::service(events = LaunchpadEvent)]
-impl LaunchpadService {
     // -------------------------------------------------------------------------
-    // ADMIN FUNCTIONS
+    // AUTOMATION
     // -------------------------------------------------------------------------
 
-    /// Pause the contract (owner only).
+    /// Let the launchpad start and finalize a launch by itself (creator only).
+    ///
+    /// Reserves `gas_for_automation` for the finalization message and
+    /// schedules activation at `start_time`, paid from this message's gas; an
+    /// active launch is scheduled for finalization straight away. If a step
+    /// fails, `AutomationFailed` is emitted and the launch falls back to
+    /// `start_launch` (open to anyone once due) and `finalize`; the creator
+    /// can also schedule it again.
     #[export(unwrap_result)]
-    pub fn pause(&mut self) -> Result<(), ContractError> {
+    pub fn schedule_launch(&mut self, launch_id: Id) -> Result<(), ContractError> {
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
         let caller = gstd::msg::source();
-        let s = storage_mut();
+        let current_block = gstd::exec::block_height();
 
-        if caller != s.owner {
+        let launch = s.launches.get_mut(&launch_id)
+            .ok_or(ContractError::NotFound)?;
+
+        if caller != launch.creator {
             return Err(ContractError::Unauthorized);
         }
 
-        s.paused = true;
-        let _ = self.emit_event(LaunchpadEvent::Paused { by: caller });
+        let (action, due_block) = match launch.status {
+            LaunchStatus::Pending => {
+                if !launch.tokens_deposited {
+                    return Err(ContractError::invalid_state("Tokens not deposited"));
+                }
+                (ScheduledAction::Start, launch.start_time)
+            }
+            LaunchStatus::Active => (ScheduledAction::Finalize, launch.finalization_block()),
+            _ => return Err(ContractError::invalid_state("Launch must be Pending or Active")),
+        };
+        launch.automated = true;
+        // Only needed until the finalization message is sent, at start_time at the latest
+        let duration = launch.end_time.saturating_sub(current_block).max(1);
+
+        if s.schedules.contains_key(&launch_id) {
+            return Err(ContractError::AlreadyExists);
+        }
+
+        let reservation = gstd::Reservation::reserve(s.gas_for_automation, duration)
+            .map_err(|_| ContractError::invalid_state("Failed to reserve gas"))?;
+        s.reservations.insert(launch_id, reservation);
+        s.schedule_step(launch_id, action, due_block)?;
+
+        let _ = self.emit_event(LaunchpadEvent::AutomationScheduled {
+            launch_id,
+            action,
+            due_block,
+        });
+
         Ok(())
     }
 
-    /// Resume the contract (owner only).
+    /// Run a launch's scheduled step (the launchpad's own messages only).
+    ///
+    /// Activates a pending launch and schedules its finalization, or
+    /// finalizes an active one, scheduling again while the sale can still
+    /// run. A step for a launch already moved on by hand only tidies up.
     #[export(unwrap_result)]
-    pub fn resume(&mut self) -> Result<(), ContractError> {
+    pub async fn run_automation(&mut self, launch_id: Id) -> Result<(), ContractError> {
+        if gstd::msg::source() != gstd::exec::program_id() {
+            return Err(ContractError::Unauthorized);
+        }
+
+        if self.advance_schedule(launch_id) {
+            if let Err(err) = self.finalize(launch_id).await {
+                let _ = self.emit_event(LaunchpadEvent::AutomationFailed {
+                    launch_id,
+                    action: ScheduledAction::Finalize,
+                    reason: error_reason(err),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply the current scheduled step short of finalizing.
+    ///
+    /// Returns whether the sale is ready to be finalized.
+    fn advance_schedule(&mut self, launch_id: Id) -> bool {
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+        let current_block = gstd::exec::block_height();
+
+        // Ignore a step that was replaced or dropped
+        let Some(schedule) = s.schedules.get(&launch_id).cloned() else {
+            return false;
+        };
+        if schedule.message_id != gstd::msg::id() {
+            return false;
+        }
+        let Some(launch) = s.launches.get_mut(&launch_id) else {
+            s.clear_schedule(launch_id);
+            return false;
+        };
+
+        if schedule.action == ScheduledAction::Start && launch.status == LaunchStatus::Pending {
+            launch.status = LaunchStatus::Active;
+
+            let _ = self.emit_event(LaunchpadEvent::LaunchStarted { launch_id });
+        }
+
+        // Started, finalized or cancelled by hand, or ready to close
+        let active = launch.status == LaunchStatus::Active;
+        let due_block = launch.finalization_block();
+        if !active || launch.is_fully_subscribed() || current_block >= due_block {
+            s.clear_schedule(launch_id);
+            return active;
+        }
+
+        match s.schedule_step(launch_id, ScheduledAction::Finalize, due_block) {
+            Ok(()) => {
+                let _ = self.emit_event(LaunchpadEvent::AutomationScheduled {
+                    launch_id,
+                    action: ScheduledAction::Finalize,
+                    due_block,
+                });
+            }
+            Err(err) => {
+                s.clear_schedule(launch_id);
+
+                let _ = self.emit_event(LaunchpadEvent::AutomationFailed {
+                    launch_id,
+                    action: ScheduledAction::Finalize,
+                    reason: error_reason(err),
+                });
+            }
+        }
+        false
+    }
+
+    /// Report a scheduled step that failed (called from the program's reply handler).
+    fn handle_automation_reply(&mut self) {
+        let failed = self.storage_mut().settle_scheduled_reply();
+        if let Some((launch_id, schedule)) = failed {
+            let _ = self.emit_event(LaunchpadEvent::AutomationFailed {
+                launch_id,
+                action: schedule.action,
+                reason: String::from("Scheduled message failed"),
+            });
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // POSITIONS
+    // -------------------------------------------------------------------------
+
+    /// Allow or forbid position transfers for a launch (creator only).
+    #[export(unwrap_result)]
+    pub fn set_position_transfers(&mut self, launch_id: Id, enabled: bool) -> Result<(), ContractError> {
+        let mut storage = self.storage_mut();
+        let caller = gstd::msg::source();
+
+        let launch = storage.launches.get_mut(&launch_id)
+            .ok_or(ContractError::NotFound)?;
+
+        if caller != launch.creator {
+            return Err(ContractError::Unauthorized);
+        }
+
+        launch.positions_transferable = enabled;
+
+        let _ = self.emit_event(LaunchpadEvent::PositionTransfersUpdated {
+            launch_id,
+            enabled,
+        });
+
+        Ok(())
+    }
+
+    /// Transfer `amount` of the caller's unclaimed tokens, vested or not, to
+    /// another address, which continues on the same vesting schedule.
+    #[export(unwrap_result)]
+    pub fn transfer_position(&mut self, launch_id: Id, to: ActorId, amount: Amount) -> Result<(), ContractError> {
+        let mut storage = self.storage_mut();
+        let caller = gstd::msg::source();
+
+        if amount == 0 {
+            return Err(ContractError::ZeroAmount);
+        }
+        if to == ActorId::default() {
+            return Err(ContractError::ZeroAddress);
+        }
+        if to == caller {
+            return Err(ContractError::invalid_input("Cannot transfer to yourself"));
+        }
+
+        let launch = storage.launches.get_mut(&launch_id)
+            .ok_or(ContractError::NotFound)?;
+
+        if !launch.positions_transferable {
+            return Err(ContractError::invalid_state("Position transfers disabled"));
+        }
+        if !launch.is_successful() {
+            return Err(ContractError::invalid_state("Launch not successful"));
+        }
+
+        launch.transfer_position(caller, to, amount)?;
+
+        let _ = self.emit_event(LaunchpadEvent::PositionTransferred {
+            launch_id,
+            from: caller,
+            to,
+            amount,
+        });
+
+        Ok(())
+    }
+
+    // -------------------------------------------------------------------------
+    // CLAIMS & REFUNDS
+    // -------------------------------------------------------------------------
+
+    /// Claim purchased tokens (for successful launches), along with any
+    /// payment owed back after settlement.
+    #[export(unwrap_result)]
+    pub async fn claim_tokens(&mut self, launch_id: Id) -> Result<Amount, ContractError> {
+        let _guard = ReentrancyGuard::start(self.storage)?;
+        self.claim_tokens_of(launch_id, gstd::msg::source()).await
+    }
+
+    /// Claim on behalf of `beneficiary` (anyone can call, e.g. a relayer).
+    ///
+    /// Tokens go to the beneficiary, or to their registered claim address.
+    #[export(unwrap_result)]
+    pub async fn claim_tokens_for(&mut self, launch_id: Id, beneficiary: ActorId) -> Result<Amount, ContractError> {
+        let _guard = ReentrancyGuard::start(self.storage)?;
+        self.claim_tokens_of(launch_id, beneficiary).await
+    }
+
+    /// Register an address to receive the caller's token claims and refunds
+    /// (e.g. a custodian or multisig); `None` restores payouts to the caller.
+    #[export(unwrap_result)]
+    pub fn set_claim_address(&mut self, claim_address: Option<ActorId>) -> Result<(), ContractError> {
+        let mut storage = self.storage_mut();
+        let caller = gstd::msg::source();
+
+        match claim_address {
+            Some(address) if address == ActorId::default() => return Err(ContractError::ZeroAddress),
+            Some(address) if address != caller => {
+                storage.claim_addresses.insert(caller, address);
+            }
+            _ => {
+                storage.claim_addresses.remove(&caller);
+            }
+        }
+
+        let _ = self.emit_event(LaunchpadEvent::ClaimAddressUpdated {
+            user: caller,
+            claim_address,
+        });
+
+        Ok(())
+    }
+
+    /// Pay `beneficiary`'s claimable tokens and owed excess to their payout address.
+    async fn claim_tokens_of(&mut self, launch_id: Id, beneficiary: ActorId) -> Result<Amount, ContractError> {
+        let current_block = gstd::exec::block_height();
+
+        // Validate and record the claim; storage is released before the await
+        let (token_address, claimable, recipient) = {
+            let mut storage = self.storage_mut();
+            let s = &mut *storage;
+
+            let launch = s.launches.get_mut(&launch_id)
+                .ok_or(ContractError::NotFound)?;
+
+            // Check status - must be in distribution phase
+            if !matches!(launch.status, LaunchStatus::DistributionPending | LaunchStatus::Succeeded) {
+                return Err(ContractError::invalid_state("Tokens not available for claim"));
+            }
+
+            // Get user's purchased tokens
+            let total_purchased = launch.tokens_purchased.get(&beneficiary).copied().unwrap_or(0);
+            if total_purchased == 0 {
+                return Err(ContractError::invalid_state("No tokens purchased"));
+            }
+
+            // Calculate claimable (with vesting if applicable)
+            let claimable = launch.claimable_tokens(&beneficiary, current_block);
+
+            let excess = launch.excess_refunds.get(&beneficiary).copied().unwrap_or(0);
+            if claimable == 0 && excess == 0 {
+                return Err(ContractError::invalid_state("Nothing to claim yet"));
+            }
+
+            // Update state BEFORE async transfer (CEI pattern)
+            *launch.claimed.entry(beneficiary).or_insert(0) += claimable;
+            (launch.token_address, claimable, s.payout_address(&beneficiary))
+        };
+
+        // Perform actual VFT transfer (a rejected transfer stays in the ledger)
+        if claimable > 0 {
+            if self.send_tracked(Some(launch_id), token_address, recipient, claimable).await.is_err() {
+                return Ok(0);
+            }
+
+            let _ = self.emit_event(LaunchpadEvent::TokensClaimed {
+                launch_id,
+                user: beneficiary,
+                amount: claimable,
+            });
+        }
+
+        self.pay_excess_refund(launch_id, beneficiary).await;
+        Ok(claimable)
+    }
+
+    /// Claim vested team tokens (team allocation beneficiaries, successful launches).
+    ///
+    /// Tokens go to the beneficiary's claim address, if one is registered.
+    #[export(unwrap_result)]
+    pub async fn claim_team_tokens(&mut self, launch_id: Id) -> Result<Amount, ContractError> {
+        let _guard = ReentrancyGuard::start(self.storage)?;
+
         let caller = gstd::msg::source();
-        let s = storage_mut();
+        let current_block = gstd::exec::block_height();
+
+        // Validate and record the claim; storage is released before the await
+        let (token_address, claimable, recipient) = {
+            let mut storage = self.storage_mut();
+            let s = &mut *storage;
+            let launch = s.launches.get_mut(&launch_id)
+                .ok_or(ContractError::NotFound)?;
+
+            if !launch.is_successful() {
+                return Err(ContractError::invalid_state("Tokens not available for claim"));
+            }
+            if !launch.team_tokens.contains_key(&caller) {
+                return Err(ContractError::Unauthorized);
+            }
+
+            let claimable = launch.claimable_team_tokens(&caller, current_block);
+            if claimable == 0 {
+                return Err(ContractError::invalid_state("Nothing to claim yet"));
+            }
+
+            *launch.team_claimed.entry(caller).or_insert(0) += claimable;
+            (launch.token_address, claimable, s.payout_address(&caller))
+        };
 
-        if caller != s.owner {
-            return Err(ContractError::Unauthorized);
+        // A rejected transfer stays in the ledger for `retry_transfer`
+        if self.send_tracked(Some(launch_id), token_address, recipient, claimable).await.is_err() {
+            return Ok(0);
         }
 
-        s.paused = false;
-        let _ = self.emit_event(LaunchpadEvent::Resumed { by: caller });
-        Ok(())
+        let _ = self.emit_event(LaunchpadEvent::TeamTokensClaimed {
+            launch_id,
+            beneficiary: caller,
+            amount: claimable,
+        });
+
+        Ok(claimable)
     }
 
-    /// Set the VFT code ID for token deployment (owner only).
+    /// Stop a beneficiary's team vesting at `at_block` (creator or admin).
+    ///
+    /// Tokens vested by then stay claimable; the rest is burned, or returned
+    /// to the creator when `burn` is false.
     #[export(unwrap_result)]
-    pub fn set_vft_code_id(&mut self, code_id: CodeId) -> Result<(), ContractError> {
+    pub async fn revoke_team_vesting(
+        &mut self,
+        launch_id: Id,
+        beneficiary: ActorId,
+        at_block: BlockNumber,
+        burn: bool,
+    ) -> Result<Amount, ContractError> {
+        let _guard = ReentrancyGuard::start(self.storage)?;
+
         let caller = gstd::msg::source();
-        let s = storage_mut();
+        let current_block = gstd::exec::block_height();
 
-        if caller != s.owner {
-            return Err(ContractError::Unauthorized);
-        }
+        // Validate and record the revocation; storage is released before the await
+        let (token_address, creator, revoked) = {
+            let mut storage = self.storage_mut();
+            let s = &mut *storage;
+            let is_admin = s.has_role(RoleId::DEFAULT_ADMIN, &caller);
 
-        // Validate code ID is not zero/default
-        if code_id == CodeId::default() {
-            return Err(ContractError::invalid_input("Invalid VFT code ID"));
-        }
+            let launch = s.launches.get_mut(&launch_id)
+                .ok_or(ContractError::NotFound)?;
 
-        s.vft_code_id = code_id;
-        Ok(())
-    }
+            if caller != launch.creator && !is_admin {
+                return Err(ContractError::Unauthorized);
+            }
+            if !launch.is_successful() {
+                return Err(ContractError::invalid_state("Launch not successful"));
+            }
+            if at_block < current_block {
+                return Err(ContractError::invalid_input("Cannot revoke vested tokens"));
+            }
+            if launch.team_revocations.contains_key(&beneficiary) {
+                return Err(ContractError::AlreadyProcessed);
+            }
 
-    /// Set the fee recipient address (owner only).
-    #[export(unwrap_result)]
-    pub fn set_fee_recipient(&mut self, recipient: ActorId) -> Result<(), ContractError> {
-        let caller = gstd::msg::source();
-        let s = storage_mut();
+            let (Some(team), Some(&allocated)) = (&launch.team_allocation, launch.team_tokens.get(&beneficiary)) else {
+                return Err(ContractError::NotFound);
+            };
+            let vested = calculate_vested_tokens(allocated, &team.vesting, at_block);
+            let revoked = allocated.saturating_sub(vested);
+            if revoked == 0 {
+                return Err(ContractError::invalid_state("Nothing left to revoke"));
+            }
 
-        if caller != s.owner {
-            return Err(ContractError::Unauthorized);
-        }
+            launch.team_revocations.insert(beneficiary, TeamRevocation {
+                at_block,
+                revoked,
+                burned: burn,
+            });
+            (launch.token_address, launch.creator, revoked)
+        };
 
-        // Validate recipient is not zero address
-        if recipient == ActorId::default() {
-            return Err(ContractError::ZeroAddress);
-        }
+        if burn {
+            if VftClient::burn(token_address, gstd::exec::program_id(), U256::from(revoked)).await.is_err() {
+                // Allow the revocation to be retried (returning Err would discard this, see contribute_vft)
+                if let Some(launch) = self.storage_mut().launches.get_mut(&launch_id) {
+                    launch.team_revocations.remove(&beneficiary);
+                }
 
-        let old = s.fee_recipient;
-        s.fee_recipient = recipient;
+                let _ = self.emit_event(LaunchpadEvent::TokenTransferFailed {
+                    launch_id,
+                    user: gstd::exec::program_id(),
+                    amount: revoked,
+                    reason: String::from("Revoked token burn failed"),
+                });
 
-        let _ = self.emit_event(LaunchpadEvent::FeeRecipientUpdated {
-            old,
-            new: recipient,
+                return Ok(0);
+            }
+        } else if self.send_tracked(Some(launch_id), token_address, creator, revoked).await.is_err() {
+            // The revocation stands; the return stays in the ledger
+            return Ok(0);
+        }
+
+        let _ = self.emit_event(LaunchpadEvent::TeamVestingRevoked {
+            launch_id,
+            beneficiary,
+            at_block,
+            revoked,
+            burned: burn,
         });
 
-        Ok(())
+        Ok(revoked)
     }
 
-    /// Set gas configuration for program deployment (owner only).
-    #[export(unwrap_result)]
-    pub fn set_gas_config(&mut self, gas_for_program: u64, gas_for_reply: u64) -> Result<(), ContractError> {
-        let caller = gstd::msg::source();
-        let s = storage_mut();
+    /// Pay out what `user` is owed back after settlement, if anything.
+    ///
+    /// Runs after an await, so a failed transfer restores the balance and
+    /// emits `TokenTransferFailed` instead of returning an error.
+    async fn pay_excess_refund(&mut self, launch_id: Id, user: ActorId) {
+        let (payment_token, excess, recipient) = {
+            let mut storage = self.storage_mut();
+            let s = &mut *storage;
+            let Some(launch) = s.launches.get_mut(&launch_id) else {
+                return;
+            };
+            let Some(excess) = launch.excess_refunds.remove(&user) else {
+                return;
+            };
+            (launch.payment_token, excess, s.payout_address(&user))
+        };
 
-        if caller != s.owner {
-            return Err(ContractError::Unauthorized);
-        }
+        if self.transfer_payment(launch_id, payment_token, user, recipient, excess).await.is_err() {
+            if let Some(launch) = self.storage_mut().launches.get_mut(&launch_id) {
+                launch.excess_refunds.insert(user, excess);
+            }
 
-        // Validate gas values are reasonable (minimum 1 billion)
-        if gas_for_program < 1_000_000_000 {
-            return Err(ContractError::invalid_input("Gas for program too low (min 1 billion)"));
-        }
-        if gas_for_reply < 1_000_000_000 {
-            return Err(ContractError::invalid_input("Gas for reply too low (min 1 billion)"));
+            let _ = self.emit_event(LaunchpadEvent::TokenTransferFailed {
+                launch_id,
+                user,
+                amount: excess,
+                reason: String::from("Excess refund transfer failed"),
+            });
+            return;
         }
 
-        s.gas_for_program = gas_for_program;
-        s.gas_for_reply = gas_for_reply;
-
-        let _ = self.emit_event(LaunchpadEvent::GasConfigUpdated {
-            gas_for_program,
-            gas_for_reply,
+        let _ = self.emit_event(LaunchpadEvent::RefundClaimed {
+            launch_id,
+            user,
+            amount: excess,
         });
-
-        Ok(())
     }
 
-    // -------------------------------------------------------------------------
-    // LAUNCH CREATION
-    // -------------------------------------------------------------------------
-
-    /// Create a new token launch with automatic token deployment.
+    /// Claim refund (for failed/cancelled launches).
     #[export(unwrap_result)]
-    pub async fn create_launch(&mut self, input: CreateLaunchInput) -> Result<Id, ContractError> {
-        let _guard = ReentrancyGuard::start()?;
-        
-        let s = storage_mut();
+    pub async fn claim_refund(&mut self, launch_id: Id) -> Result<Amount, ContractError> {
+        let caller = gstd::msg::source();
 
-        if s.paused {
-            return Err(ContractError::invalid_state("Contract is paused"));
-        }
+        // Validate and take the contribution; storage is released before the await
+        let (payment_token, contribution, refund, recipient) = {
+            let mut storage = self.storage_mut();
+            let s = &mut *storage;
 
-        let creator = gstd::msg::source();
-        let current_block = gstd::exec::block_height();
+            let launch = s.launches.get_mut(&launch_id)
+                .ok_or(ContractError::NotFound)?;
 
-        // Validate input parameters
-        if input.token_name.is_empty() {
-            return Err(ContractError::invalid_input("Token name cannot be empty"));
-        }
-        if input.token_name.len() > 64 {
-            return Err(ContractError::invalid_input("Token name too long (max 64 chars)"));
-        }
-        if input.token_symbol.is_empty() {
-            return Err(ContractError::invalid_input("Token symbol cannot be empty"));
-        }
-        if input.token_symbol.len() > 10 {
-            return Err(ContractError::invalid_input("Token symbol too long (max 10 chars)"));
-        }
-        if input.title.is_empty() {
-            return Err(ContractError::invalid_input("Title cannot be empty"));
-        }
-        if input.total_tokens == 0 {
-            return Err(ContractError::invalid_input("Total tokens must be > 0"));
-        }
-        if input.price_per_token == 0 {
-            return Err(ContractError::invalid_input("Price per token must be > 0"));
-        }
-        if input.start_time >= input.end_time {
-            return Err(ContractError::invalid_input("Start time must be before end time"));
-        }
-        if input.start_time <= current_block {
-            return Err(ContractError::invalid_input("Start time must be in the future"));
-        }
-        if input.min_raise > input.max_raise {
-            return Err(ContractError::invalid_input("Min raise must be <= max raise"));
-        }
-        if input.max_per_wallet == 0 {
-            return Err(ContractError::invalid_input("Max per wallet must be > 0"));
-        }
+            // Check status
+            if !matches!(launch.status, LaunchStatus::RefundAvailable | LaunchStatus::Failed | LaunchStatus::Cancelled) {
+                return Err(ContractError::invalid_state("Refunds not available"));
+            }
 
-        // Validate max_raise doesn't exceed what tokens can cover
-        let max_possible_raise = input.total_tokens.saturating_mul(input.price_per_token);
-        if input.max_raise > max_possible_raise {
-            return Err(ContractError::invalid_input("Max raise exceeds token value"));
-        }
+            // Get contribution
+            let contribution = launch.contributions.remove(&caller)
+                .ok_or(ContractError::invalid_state("No contribution to refund"))?;
 
-        // Validate vesting configuration if provided
-        if let Some(ref vesting) = input.vesting_config {
-            if vesting.vesting_end() < input.end_time {
-                return Err(ContractError::invalid_input("Vesting must end after launch ends"));
+            if contribution == 0 {
+                return Err(ContractError::ZeroAmount);
             }
-        }
 
-        // Check if VFT code ID is set
-        if s.vft_code_id == CodeId::default() {
-            return Err(ContractError::invalid_state("VFT code ID not set"));
-        }
+            (launch.payment_token, contribution, launch.refund_for(contribution), s.payout_address(&caller))
+        };
 
-        // Get gas configuration
-        let gas_for_program = s.gas_for_program;
-        let gas_for_reply = s.gas_for_reply;
+        // Transfer refund
+        let transfer_result = self.transfer_payment(launch_id, payment_token, caller, recipient, refund).await;
 
-        // Deploy the token contract with configurable gas
-        let token_address = VftFactory::deploy_token(
-            input.token_name.clone(),
-            input.token_symbol.clone(),
-            U256::from(input.total_tokens),
-            s.vft_code_id,
-            gas_for_program,
-            gas_for_reply,
-        ).await?;
+        {
+            let mut storage = self.storage_mut();
+            let launch = storage.launches.get_mut(&launch_id)
+                .ok_or(ContractError::NotFound)?;
 
-        let launch_id = s.next_launch_id;
-        s.next_launch_id = s.next_launch_id
-            .checked_add(1)
-            .ok_or(ContractError::Overflow)?;
+            if transfer_result.is_err() {
+                // Restore the contribution so the refund can be claimed again
+                // (returning Err would discard this rollback, see contribute_vft)
+                launch.contributions.insert(caller, contribution);
 
-        let launch = Launch {
-            id: launch_id,
-            creator,
-            title: input.title.clone(),
-            description: input.description,
-            token_address,  // Use the deployed token address
-            total_tokens: input.total_tokens,
-            tokens_remaining: input.total_tokens,
-            price_per_token: input.price_per_token,
-            min_raise: input.min_raise,
-            max_raise: input.max_raise,
-            total_raised: 0,
-            max_per_wallet: input.max_per_wallet,
-            start_time: input.start_time,
-            end_time: input.end_time,
-            whitelist: BTreeSet::new(),
-            whitelist_enabled: input.whitelist_enabled,
-            contributions: BTreeMap::new(),
-            tokens_purchased: BTreeMap::new(),
-            claimed: BTreeMap::new(),
-            vesting_config: input.vesting_config,
-            status: LaunchStatus::Pending,
-            created_at: current_block,
-            tokens_deposited: true,  // Tokens are minted directly to contract
-            funds_withdrawn: false,
-            refunds_processed: false,
-            contributors: Vec::new(),
-            finalization_deadline: input.end_time.saturating_add(FINALIZATION_GRACE_PERIOD),
-        };
+                let _ = self.emit_event(LaunchpadEvent::TokenTransferFailed {
+                    launch_id,
+                    user: caller,
+                    amount: refund,
+                    reason: String::from("Refund transfer failed"),
+                });
 
-        s.launches.insert(launch_id, launch);
+                return Ok(0);
+            }
 
-        // Emit TokenDeployed event for the newly created token
-        let _ = self.emit_event(LaunchpadEvent::TokenDeployed {
-            launch_id,
-            token_address,
-            name: input.token_name,
-            symbol: input.token_symbol,
-            total_supply: input.total_tokens,
-        });
+            let _ = self.emit_event(LaunchpadEvent::RefundClaimed {
+                launch_id,
+                user: caller,
+                amount: refund,
+            });
 
-        let _ = self.emit_event(LaunchpadEvent::LaunchCreated {
-            launch_id,
-            creator,
-            title: input.title,
-            token_address,
-            total_tokens: input.total_tokens,
-            price_per_token: input.price_per_token,
-            min_raise: input.min_raise,
-            max_raise: input.max_raise,
-            start_time: input.start_time,
-            end_time: input.end_time,
-        });
+            // Check if all refunds processed
+            if launch.contributions.is_empty() {
+                launch.refunds_processed = true;
+                launch.status = LaunchStatus::Finalized;
+                let _ = self.emit_event(LaunchpadEvent::LaunchFinalized { launch_id });
+            }
+        }
 
-        Ok(launch_id)
+        // A settled auction may still owe the contributor its excess
+        self.pay_excess_refund(launch_id, caller).await;
+
+        Ok(refund)
     }
 
-    /// Add addresses to whitelist.
+    /// Push claimable tokens (and owed excess payments) to contributors
+    /// `start..start + count` of a successful launch (KEEPER role).
+    ///
+    /// `start` must equal `distribution_cursor`, which moves past the page and
+    /// wraps to 0 after the last one. Returns the number of contributors paid.
     #[export(unwrap_result)]
-    pub fn add_to_whitelist(
-        &mut self,
-        launch_id: Id,
-        addresses: Vec<ActorId>,
-    ) -> Result<(), ContractError> {
-        let s = storage_mut();
-        let caller = gstd::msg::source();
+    pub async fn distribute_batch(&mut self, launch_id: Id, start: u32, count: u32) -> Result<u32, ContractError> {
+        let _guard = BatchGuard::start(self.storage, launch_id)?;
+        let current_block = gstd::exec::block_height();
 
-        let launch = s.launches.get_mut(&launch_id)
-            .ok_or(ContractError::NotFound)?;
+        let (page, end) = {
+            let s = self.storage();
+            s.ensure_role(roles::KEEPER, &gstd::msg::source())?;
 
-        if caller != launch.creator {
-            return Err(ContractError::Unauthorized);
-        }
+            let launch = s.launches.get(&launch_id)
+                .ok_or(ContractError::NotFound)?;
 
-        // Can only modify whitelist before launch starts (Pending status only)
-        if launch.status != LaunchStatus::Pending {
-            return Err(ContractError::invalid_state("Cannot modify whitelist after launch starts"));
-        }
+            if !launch.is_successful() {
+                return Err(ContractError::invalid_state("Tokens not available for claim"));
+            }
+            if start != launch.distribution_cursor {
+                return Err(ContractError::invalid_input("Batch must start at the distribution cursor"));
+            }
+            let (page, end) = batch_page(&launch.contributors, start, count)?;
+            let page: Vec<ActorId> = page
+                .iter()
+                .filter(|user| launch.claimable_tokens(user, current_block) > 0 || launch.excess_refunds.contains_key(user))
+                .copied()
+                .collect();
+            (page, end)
+        };
 
-        let count = addresses.len() as u32;
-        for addr in addresses {
-            launch.whitelist.insert(addr);
+        let mut paid = 0;
+        for user in page {
+            if self.push_tokens(launch_id, user, current_block).await {
+                paid += 1;
+            }
+            self.pay_excess_refund(launch_id, user).await;
         }
 
-        self.emit_event(LaunchpadEvent::WhitelistUpdated {
-            launch_id,
-            addresses_added: count,
-        });
+        if let Some(launch) = self.storage_mut().launches.get_mut(&launch_id) {
+            launch.distribution_cursor = next_cursor(&launch.contributors, end);
+        }
 
-        Ok(())
+        Ok(paid)
     }
 
-    /// Start the launch (creator only).
+    /// Push refunds to contributors `start..start + count` of a failed or
+    /// cancelled launch (KEEPER role).
+    ///
+    /// `start` must equal `refund_cursor`, which moves past the page and wraps
+    /// to 0 after the last one. Returns the number of contributors refunded.
     #[export(unwrap_result)]
-    pub fn start_launch(&mut self, launch_id: Id) -> Result<(), ContractError> {
-        let s = storage_mut();
-        let caller = gstd::msg::source();
+    pub async fn refund_batch(&mut self, launch_id: Id, start: u32, count: u32) -> Result<u32, ContractError> {
+        let _guard = BatchGuard::start(self.storage, launch_id)?;
 
-        let launch = s.launches.get_mut(&launch_id)
-            .ok_or(ContractError::NotFound)?;
+        let (page, end) = {
+            let s = self.storage();
+            s.ensure_role(roles::KEEPER, &gstd::msg::source())?;
 
-        if caller != launch.creator {
-            return Err(ContractError::Unauthorized);
+            let launch = s.launches.get(&launch_id)
+                .ok_or(ContractError::NotFound)?;
+
+            if !matches!(launch.status, LaunchStatus::RefundAvailable | LaunchStatus::Failed | LaunchStatus::Cancelled) {
+                return Err(ContractError::invalid_state("Refunds not available"));
+            }
+            if start != launch.refund_cursor {
+                return Err(ContractError::invalid_input("Batch must start at the refund cursor"));
+            }
+            let (page, end) = batch_page(&launch.contributors, start, count)?;
+            let page: Vec<ActorId> = page
+                .iter()
+                .filter(|user| launch.contributions.get(user).is_some_and(|c| *c > 0))
+                .copied()
+                .collect();
+            (page, end)
+        };
+
+        let mut refunded = 0;
+        for user in page {
+            if self.push_refund(launch_id, user).await {
+                refunded += 1;
+            }
         }
 
-        if launch.status != LaunchStatus::Pending {
-            return Err(ContractError::invalid_state("Launch must be in Pending state"));
+        if let Some(launch) = self.storage_mut().launches.get_mut(&launch_id) {
+            launch.refund_cursor = next_cursor(&launch.contributors, end);
         }
 
-        // Tokens are already minted to contract during creation
-        launch.status = LaunchStatus::Active;
+        Ok(refunded)
+    }
 
-        let _ = self.emit_event(LaunchpadEvent::LaunchStarted { launch_id });
+    /// Send `user` their claimable tokens; returns whether anything was sent.
+    ///
+    /// A rejected transfer stays in the ledger for `retry_transfer`.
+    async fn push_tokens(&mut self, launch_id: Id, user: ActorId, current_block: BlockNumber) -> bool {
+        let (token_address, claimable, recipient) = {
+            let mut storage = self.storage_mut();
+            let s = &mut *storage;
+            let Some(launch) = s.launches.get_mut(&launch_id) else {
+                return false;
+            };
+            let claimable = launch.claimable_tokens(&user, current_block);
+            if claimable == 0 {
+                return false;
+            }
+            *launch.claimed.entry(user).or_insert(0) += claimable;
+            (launch.token_address, claimable, s.payout_address(&user))
+        };
 
-        Ok(())
+        if self.send_tracked(Some(launch_id), token_address, recipient, claimable).await.is_err() {
+            return false;
+        }
+
+        let _ = self.emit_event(LaunchpadEvent::TokensClaimed {
+            launch_id,
+            user,
+            amount: claimable,
+        });
+        true
     }
 
+    /// Refund `user`'s contribution; returns whether anything was sent.
+    ///
+    /// A failed transfer restores the contribution and is reported via
+    /// `TokenTransferFailed`.
+    async fn push_refund(&mut self, launch_id: Id, user: ActorId) -> bool {
+        let (payment_token, contribution, refund, recipient) = {
+            let mut storage = self.storage_mut();
+            let s = &mut *storage;
+            let Some(launch) = s.launches.get_mut(&launch_id) else {
+                return false;
+            };
+            let Some(contribution) = launch.contributions.remove(&user) else {
+                return false;
+            };
+            (launch.payment_token, contribution, launch.refund_for(contribution), s.payout_address(&user))
+        };
 
-    // -------------------------------------------------------------------------
-    // CONTRIBUTIONS
-    // -------------------------------------------------------------------------
+        let transfer_result = self.transfer_payment(launch_id, payment_token, user, recipient, refund).await;
 
-    /// Contribute to a launch.
-    #[export(unwrap_result)]
-    pub fn contribute(&mut self, launch_id: Id) -> Result<Amount, ContractError> {
-        let s = storage_mut();
+        {
+            let mut storage = self.storage_mut();
+            let Some(launch) = storage.launches.get_mut(&launch_id) else {
+                return false;
+            };
+
+            if transfer_result.is_err() {
+                launch.contributions.insert(user, contribution);
+
+                let _ = self.emit_event(LaunchpadEvent::TokenTransferFailed {
+                    launch_id,
+                    user,
+                    amount: refund,
+                    reason: String::from("Refund transfer failed"),
+                });
+                return false;
+            }
+
+            let _ = self.emit_event(LaunchpadEvent::RefundClaimed {
+                launch_id,
+                user,
+                amount: refund,
+            });
 
-        if s.paused {
-            return Err(ContractError::invalid_state("Contract is paused"));
+            if launch.contributions.is_empty() {
+                launch.refunds_processed = true;
+                launch.status = LaunchStatus::Finalized;
+                let _ = self.emit_event(LaunchpadEvent::LaunchFinalized { launch_id });
+            }
         }
 
-        let contributor = gstd::msg::source();
-        let value = gstd::msg::value() as Amount;
-        let current_block = gstd::exec::block_height();
+        self.pay_excess_refund(launch_id, user).await;
+        true
+    }
 
-        let launch = s.launches.get_mut(&launch_id)
-            .ok_or(ContractError::NotFound)?;
+    /// Admin force refund for stuck contributions (REFUND_OPERATOR role, after finalization deadline).
+    /// This allows the admin to refund stuck user contributions when the creator disappears.
+    #[export(unwrap_result)]
+    pub async fn admin_force_refund(&mut self, launch_id: Id, user: ActorId) -> Result<Amount, ContractError> {
+        let caller = gstd::msg::source();
+        let current_block = gstd::exec::block_height();
 
-        // Status check
-        if launch.status != LaunchStatus::Active {
-            // Refund and return error
-            let _ = transfer_native(contributor, value);
-            return Err(ContractError::invalid_state("Launch is not active"));
-        }
+        // Validate and take the contribution; storage is released before the await
+        let (payment_token, contribution, refund, recipient) = {
+            let mut storage = self.storage_mut();
+            let s = &mut *storage;
 
-        // Time window check
-        if !launch.is_in_time_window(current_block) {
-            let _ = transfer_native(contributor, value);
-            return Err(ContractError::invalid_state("Outside contribution window"));
-        }
+            s.ensure_role(roles::REFUND_OPERATOR, &caller)?;
 
-        // Whitelist check
-        if !launch.can_participate(&contributor) {
-            let _ = transfer_native(contributor, value);
-            return Err(ContractError::invalid_state("Not whitelisted"));
-        }
+            let launch = s.launches.get_mut(&launch_id)
+                .ok_or(ContractError::NotFound)?;
 
-        // Check if fully subscribed
-        if launch.is_fully_subscribed() {
-            let _ = transfer_native(contributor, value);
-            return Err(ContractError::invalid_state("Sale is fully subscribed"));
-        }
+            // Only works if finalization deadline has passed
+            if current_block <= launch.finalization_deadline {
+                return Err(ContractError::DeadlineNotPassed);
+            }
 
-        // Calculate maximum contribution
-        let wallet_remaining = launch.remaining_allocation(&contributor);
-        let raise_remaining = launch.max_raise.saturating_sub(launch.total_raised);
-        let max_contribution = wallet_remaining.min(raise_remaining);
+            // Only works for refundable states
+            if !matches!(launch.status, LaunchStatus::RefundAvailable | LaunchStatus::Failed | LaunchStatus::Cancelled) {
+                return Err(ContractError::invalid_state("Launch is not in refundable state"));
+            }
 
-        if max_contribution == 0 {
-            let _ = transfer_native(contributor, value);
-            return Err(ContractError::invalid_state("No allocation remaining"));
-        }
+            // Get contribution
+            let contribution = launch.contributions.remove(&user)
+                .ok_or(ContractError::invalid_state("No contribution to refund for this user"))?;
 
-        // Calculate actual contribution
-        let actual_contribution = value.min(max_contribution);
+            if contribution == 0 {
+                return Err(ContractError::ZeroAmount);
+            }
 
-        // Calculate tokens to purchase
-        let tokens_to_purchase = launch.tokens_for_amount(actual_contribution);
+            (launch.payment_token, contribution, launch.refund_for(contribution), s.payout_address(&user))
+        };
 
-        // Handle edge case: contribution too small for even 1 token
-        if tokens_to_purchase == 0 {
-            let _ = transfer_native(contributor, value);
-            return Err(ContractError::invalid_input("Contribution too small for any tokens"));
-        }
+        // Transfer refund to user
+        let transfer_result = self.transfer_payment(launch_id, payment_token, user, recipient, refund).await;
 
-        // Check token availability
-        let tokens_to_purchase = tokens_to_purchase.min(launch.tokens_remaining);
-        let actual_contribution = launch.cost_for_tokens(tokens_to_purchase);
-        let refund = value.saturating_sub(actual_contribution);
+        let mut storage = self.storage_mut();
+        let launch = storage.launches.get_mut(&launch_id)
+            .ok_or(ContractError::NotFound)?;
 
-        // Update state
-        *launch.contributions.entry(contributor).or_insert(0) += actual_contribution;
-        *launch.tokens_purchased.entry(contributor).or_insert(0) += tokens_to_purchase;
-        launch.total_raised = launch.total_raised.saturating_add(actual_contribution);
-        launch.tokens_remaining = launch.tokens_remaining.saturating_sub(tokens_to_purchase);
+        if transfer_result.is_err() {
+            // Restore the contribution so the refund can be retried
+            launch.contributions.insert(user, contribution);
 
-        // Track contributor
-        if !launch.contributors.contains(&contributor) {
-            launch.contributors.push(contributor);
-        }
+            let _ = self.emit_event(LaunchpadEvent::TokenTransferFailed {
+                launch_id,
+                user,
+                amount: refund,
+                reason: String::from("Refund transfer failed"),
+            });
 
-        // Refund excess
-        if refund > 0 {
-            let _ = transfer_native(contributor, refund);
+            return Ok(0);
         }
 
-        self.emit_event(LaunchpadEvent::Contributed {
+        let _ = self.emit_event(LaunchpadEvent::AdminForceRefund {
             launch_id,
-            contributor,
-            amount: actual_contribution,
-            tokens_purchased: tokens_to_purchase,
-            refunded: refund,
+            user,
+            amount: refund,
         });
 
-        // Check if fully subscribed now
-        if launch.is_fully_subscribed() {
-            self.emit_event(LaunchpadEvent::SaleFullySubscribed {
-                launch_id,
-                total_raised: launch.total_raised,
-            });
+        // Check if all refunds processed
+        if launch.contributions.is_empty() {
+            launch.refunds_processed = true;
+            launch.status = LaunchStatus::Finalized;
+            let _ = self.emit_event(LaunchpadEvent::LaunchFinalized { launch_id });
         }
 
-        Ok(tokens_to_purchase)
+        Ok(refund)
     }
 
-    // -------------------------------------------------------------------------
-    // FINALIZATION
-    // -------------------------------------------------------------------------
-
-    /// Finalize launch after end time (anyone can call).
+    /// Return deposited tokens that were never claimed to creator when launch fails.
+    ///
+    /// Returns 0 if the token rejects the transfer; it then stays in the
+    /// transfer ledger for `retry_transfer`.
     #[export(unwrap_result)]
-    pub fn finalize(&mut self, launch_id: Id) -> Result<(), ContractError> {
-        let s = storage_mut();
-        let current_block = gstd::exec::block_height();
+    pub async fn return_tokens_on_failure(&mut self, launch_id: Id) -> Result<Amount, ContractError> {
+        let _guard = ReentrancyGuard::start(self.storage)?;
 
-        let launch = s.launches.get_mut(&launch_id)
-            .ok_or(ContractError::NotFound)?;
+        let caller = gstd::msg::source();
 
-        // Can only finalize active launches
-        if launch.status != LaunchStatus::Active {
-            return Err(ContractError::invalid_state("Launch must be Active to finalize"));
-        }
+        // Validate and settle state; storage is released before the await
+        let (token_address, creator, amount) = {
+            let mut storage = self.storage_mut();
+            let s = &mut *storage;
+            let is_admin = s.has_role(RoleId::DEFAULT_ADMIN, &caller);
 
-        // Check if end time passed or fully subscribed
-        if current_block <= launch.end_time && !launch.is_fully_subscribed() {
-            return Err(ContractError::invalid_state("Launch has not ended yet"));
-        }
+            let launch = s.launches.get_mut(&launch_id)
+                .ok_or(ContractError::NotFound)?;
 
-        // Determine outcome
-        let reason = if launch.is_fully_subscribed() {
-            "Fully subscribed"
-        } else {
-            "Time expired"
+            // Only creator or an admin can return tokens
+            if caller != launch.creator && !is_admin {
+                return Err(ContractError::Unauthorized);
+            }
+
+            // Check if launch failed or was cancelled (a refunded launch ends up Finalized)
+            let refunded = launch.status == LaunchStatus::Finalized && launch.refunds_processed;
+            if !refunded && !matches!(launch.status, LaunchStatus::Failed | LaunchStatus::Cancelled | LaunchStatus::RefundAvailable) {
+                return Err(ContractError::invalid_state("Launch must be failed or cancelled"));
+            }
+
+            // Check if tokens were deposited
+            if !launch.tokens_deposited {
+                return Err(ContractError::invalid_state("No tokens were deposited"));
+            }
+
+            // Ensure refunds are processed first
+            if !launch.contributions.is_empty() {
+                return Err(ContractError::invalid_state("Refunds must be processed first"));
+            }
+
+            // Tokens already paid out (e.g. before an abandonment) are no longer held
+            launch.tokens_deposited = false;
+            (launch.token_address, launch.creator, launch.tokens_held())
         };
 
-        // Emit sale ended
-        self.emit_event(LaunchpadEvent::SaleEnded {
+        // Return all tokens to creator
+        if self.send_tracked(Some(launch_id), token_address, creator, amount).await.is_err() {
+            return Ok(0);
+        }
+
+        let _ = self.emit_event(LaunchpadEvent::TokensClaimed {
             launch_id,
-            total_raised: launch.total_raised,
-            total_contributors: launch.contributors.len() as u32,
-            reason: String::from(reason),
+            user: creator,
+            amount,
         });
 
-        launch.status = LaunchStatus::Ended;
-
-        // Determine success or failure
-        if launch.min_raise_met() {
-            launch.status = LaunchStatus::Succeeded;
+        Ok(amount)
+    }
 
-            self.emit_event(LaunchpadEvent::LaunchSucceeded {
-                launch_id,
-                total_raised: launch.total_raised,
-            });
+    // -------------------------------------------------------------------------
+    // MILESTONES
+    // -------------------------------------------------------------------------
 
-            // Move to distribution pending
-            launch.status = LaunchStatus::DistributionPending;
+    /// Mark the next milestone as completed (admin only).
+    #[export(unwrap_result)]
+    pub fn complete_milestone(&mut self, launch_id: Id, index: u32) -> Result<Amount, ContractError> {
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+        let caller = gstd::msg::source();
 
-            self.emit_event(LaunchpadEvent::DistributionPending { launch_id });
-        } else {
-            launch.status = LaunchStatus::Failed;
+        s.ensure_role(RoleId::DEFAULT_ADMIN, &caller)?;
 
-            self.emit_event(LaunchpadEvent::LaunchFailed {
-                launch_id,
-                total_raised: launch.total_raised,
-                min_raise: launch.min_raise,
-            });
+        let launch = s.launches.get_mut(&launch_id)
+            .ok_or(ContractError::NotFound)?;
 
-            // Move to refund available
-            launch.status = LaunchStatus::RefundAvailable;
+        launch.ensure_next_milestone(index)?;
+        let tranche = launch.complete_milestone(index);
 
-            self.emit_event(LaunchpadEvent::RefundsAvailable {
-                launch_id,
-                total_to_refund: launch.total_raised,
-                num_contributors: launch.contributors.len() as u32,
-            });
-        }
+        let _ = self.emit_event(LaunchpadEvent::MilestoneCompleted {
+            launch_id,
+            index,
+            tranche,
+        });
 
-        Ok(())
+        Ok(tranche)
     }
 
-    /// Cancel launch (creator or owner only, before contributions or any time by owner).
+    /// Approve the next milestone as a contributor.
+    ///
+    /// The milestone completes once approvers hold more than half of the raise.
     #[export(unwrap_result)]
-    pub fn cancel_launch(&mut self, launch_id: Id) -> Result<(), ContractError> {
-        let s = storage_mut();
+    pub fn approve_milestone(&mut self, launch_id: Id, index: u32) -> Result<bool, ContractError> {
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
         let caller = gstd::msg::source();
 
         let launch = s.launches.get_mut(&launch_id)
             .ok_or(ContractError::NotFound)?;
 
-        // Idempotency check - prevent re-cancellation of already processed launches
-        if matches!(launch.status, LaunchStatus::Cancelled | LaunchStatus::RefundAvailable | LaunchStatus::Finalized) {
-            return Err(ContractError::AlreadyProcessed);
-        }
-
-        // Authorization check
-        let is_creator = caller == launch.creator;
-        let is_owner = caller == s.owner;
-
-        if !is_creator && !is_owner {
+        if launch.contributions.get(&caller).copied().unwrap_or(0) == 0 {
             return Err(ContractError::Unauthorized);
         }
 
-        // Creator can only cancel in Pending state (before contributions)
-        // Owner can cancel any time (emergency)
-        if is_creator && launch.status != LaunchStatus::Pending {
-            return Err(ContractError::invalid_state("Creator can only cancel pending launches"));
+        launch.ensure_next_milestone(index)?;
+
+        if !launch.milestone_approvals.entry(index).or_default().insert(caller) {
+            return Err(ContractError::AlreadyProcessed);
         }
 
-        launch.status = LaunchStatus::Cancelled;
+        let approval_weight = launch.milestone_approval_weight(index);
 
-        self.emit_event(LaunchpadEvent::LaunchCancelled {
+        let _ = self.emit_event(LaunchpadEvent::MilestoneApproved {
             launch_id,
-            by: caller,
+            index,
+            contributor: caller,
+            approval_weight,
         });
 
-        // If there were contributions, enable refunds
-        if launch.total_raised > 0 {
-            launch.status = LaunchStatus::RefundAvailable;
-
-            self.emit_event(LaunchpadEvent::RefundsAvailable {
-                launch_id,
-                total_to_refund: launch.total_raised,
-                num_contributors: launch.contributors.len() as u32,
-            });
-        } else {
-            launch.status = LaunchStatus::Finalized;
-            self.emit_event(LaunchpadEvent::LaunchFinalized { launch_id });
+        // Simple majority of contributed funds
+        if approval_weight.saturating_mul(2) <= launch.total_raised {
+            return Ok(false);
         }
 
-        Ok(())
-    }
+        let tranche = launch.complete_milestone(index);
 
-    // -------------------------------------------------------------------------
-    // CLAIMS & REFUNDS
-    // -------------------------------------------------------------------------
+        let _ = self.emit_event(LaunchpadEvent::MilestoneCompleted {
+            launch_id,
+            index,
+            tranche,
+        });
+
+        Ok(true)
+    }
 
-    /// Claim purchased tokens (for successful launches).
+    /// Abandon a milestone launch (admin only).
+    ///
+    /// Funds not yet released to the creator or paired into the pool become
+    /// refundable pro-rata.
     #[export(unwrap_result)]
-    pub async fn claim_tokens(&mut self, launch_id: Id) -> Result<Amount, ContractError> {
-        let _guard = ReentrancyGuard::start()?;
-        
-        let s = storage_mut();
+    pub fn abandon_launch(&mut self, launch_id: Id) -> Result<Amount, ContractError> {
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
         let caller = gstd::msg::source();
-        let current_block = gstd::exec::block_height();
+
+        s.ensure_role(RoleId::DEFAULT_ADMIN, &caller)?;
 
         let launch = s.launches.get_mut(&launch_id)
             .ok_or(ContractError::NotFound)?;
 
-        // Check status - must be in distribution phase
-        if !matches!(launch.status, LaunchStatus::DistributionPending | LaunchStatus::Succeeded) {
-            return Err(ContractError::invalid_state("Tokens not available for claim"));
+        if launch.milestones.is_empty() {
+            return Err(ContractError::invalid_state("Launch has no milestones"));
         }
 
-        // Get user's purchased tokens
-        let total_purchased = launch.tokens_purchased.get(&caller).copied().unwrap_or(0);
-        if total_purchased == 0 {
-            return Err(ContractError::invalid_state("No tokens purchased"));
+        if !launch.is_successful() {
+            return Err(ContractError::invalid_state("Launch not successful"));
         }
 
-        // Calculate claimable (with vesting if applicable)
-        let claimable = if let Some(ref vesting) = launch.vesting_config {
-            let vested = calculate_vested_tokens(total_purchased, vesting, current_block);
-            let already_claimed = launch.claimed.get(&caller).copied().unwrap_or(0);
-            vested.saturating_sub(already_claimed)
-        } else {
-            let already_claimed = launch.claimed.get(&caller).copied().unwrap_or(0);
-            total_purchased.saturating_sub(already_claimed)
-        };
-
-        if claimable == 0 {
-            return Err(ContractError::invalid_state("Nothing to claim yet"));
+        let total_to_refund = launch.retained_funds();
+        if total_to_refund == 0 {
+            return Err(ContractError::invalid_state("All funds already released"));
         }
 
-        // Update state BEFORE async transfer (CEI pattern)
-        *launch.claimed.entry(caller).or_insert(0) += claimable;
+        launch.status = LaunchStatus::RefundAvailable;
 
-        // Perform actual VFT transfer
-        let transfer_result = VftClient::transfer(
-            launch.token_address,
-            caller,
-            U256::from(claimable),
-        ).await;
+        let _ = self.emit_event(LaunchpadEvent::LaunchAbandoned {
+            launch_id,
+            total_to_refund,
+        });
 
-        match transfer_result {
-            Ok(()) => {
-                self.emit_event(LaunchpadEvent::TokensClaimed {
-                    launch_id,
-                    user: caller,
-                    amount: claimable,
-                });
-                Ok(claimable)
-            }
-            Err(_) => {
-                // Rollback state on transfer failure
-                *launch.claimed.entry(caller).or_insert(0) -= claimable;
-                
-                self.emit_event(LaunchpadEvent::TokenTransferFailed {
-                    launch_id,
-                    user: caller,
-                    amount: claimable,
-                    reason: String::from("VFT transfer failed"),
-                });
-                
-                Err(ContractError::TransferFailed)
-            }
-        }
+        let _ = self.emit_event(LaunchpadEvent::RefundsAvailable {
+            launch_id,
+            total_to_refund,
+            num_contributors: launch.contributions.len() as u32,
+        });
+
+        Ok(total_to_refund)
     }
 
-    /// Claim refund (for failed/cancelled launches).
+    // -------------------------------------------------------------------------
+    // GOVERNANCE
+    // -------------------------------------------------------------------------
+
+    /// Open a governance proposal (contributors only).
     #[export(unwrap_result)]
-    pub fn claim_refund(&mut self, launch_id: Id) -> Result<Amount, ContractError> {
-        let s = storage_mut();
+    pub fn create_proposal(&mut self, launch_id: Id, action: ProposalAction) -> Result<u32, ContractError> {
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
         let caller = gstd::msg::source();
+        let current_block = gstd::exec::block_height();
 
-        let launch = s.launches.get_mut(&launch_id)
+        let launch = s.launches.get(&launch_id)
             .ok_or(ContractError::NotFound)?;
 
-        // Check status
-        if !matches!(launch.status, LaunchStatus::RefundAvailable | LaunchStatus::Failed | LaunchStatus::Cancelled) {
-            return Err(ContractError::invalid_state("Refunds not available"));
+        let governance = launch.governance.as_ref()
+            .ok_or(ContractError::invalid_state("Governance not enabled"))?;
+
+        if launch.contributions.get(&caller).copied().unwrap_or(0) == 0 {
+            return Err(ContractError::Unauthorized);
         }
 
-        // Get contribution
-        let contribution = launch.contributions.remove(&caller)
-            .ok_or(ContractError::invalid_state("No contribution to refund"))?;
+        launch.ensure_proposal_action(&action)?;
 
-        if contribution == 0 {
-            return Err(ContractError::ZeroAmount);
+        let voting_ends = current_block.saturating_add(governance.voting_period);
+
+        // Sale-time actions must be decided while the sale is still running
+        if !matches!(action, ProposalAction::ApproveMilestone(_)) && voting_ends >= launch.end_time {
+            return Err(ContractError::invalid_state("Voting would end after the sale"));
         }
 
-        // Transfer refund
-        transfer_native(caller, contribution)?;
+        let proposals = s.proposals.entry(launch_id).or_default();
+        let proposal_id = proposals.len() as u32;
 
-        self.emit_event(LaunchpadEvent::RefundClaimed {
+        proposals.push(Proposal {
+            id: proposal_id,
             launch_id,
-            user: caller,
-            amount: contribution,
+            proposer: caller,
+            action: action.clone(),
+            votes_for: 0,
+            votes_against: 0,
+            votes_abstain: 0,
+            voters: BTreeMap::new(),
+            voting_ends,
+            status: Status::Active,
         });
 
-        // Check if all refunds processed
-        if launch.contributions.is_empty() {
-            launch.refunds_processed = true;
-            launch.status = LaunchStatus::Finalized;
-            self.emit_event(LaunchpadEvent::LaunchFinalized { launch_id });
-        }
+        let _ = self.emit_event(LaunchpadEvent::ProposalCreated {
+            launch_id,
+            proposal_id,
+            proposer: caller,
+            action,
+            voting_ends,
+        });
 
-        Ok(contribution)
+        Ok(proposal_id)
     }
 
-    /// Admin force refund for stuck contributions (owner only, after finalization deadline).
-    /// This allows the admin to refund stuck user contributions when the creator disappears.
+    /// Vote on a proposal, weighted by the caller's contribution.
     #[export(unwrap_result)]
-    pub fn admin_force_refund(&mut self, launch_id: Id, user: ActorId) -> Result<Amount, ContractError> {
-        let s = storage_mut();
+    pub fn vote(&mut self, launch_id: Id, proposal_id: u32, choice: VoteChoice) -> Result<Amount, ContractError> {
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
         let caller = gstd::msg::source();
         let current_block = gstd::exec::block_height();
 
-        if caller != s.owner {
+        let launch = s.launches.get(&launch_id)
+            .ok_or(ContractError::NotFound)?;
+
+        let weight = launch.contributions.get(&caller).copied().unwrap_or(0);
+        if weight == 0 {
             return Err(ContractError::Unauthorized);
         }
 
-        let launch = s.launches.get_mut(&launch_id)
+        let proposal = s.proposals
+            .get_mut(&launch_id)
+            .and_then(|p| p.get_mut(proposal_id as usize))
             .ok_or(ContractError::NotFound)?;
 
-        // Only works if finalization deadline has passed
-        if current_block <= launch.finalization_deadline {
-            return Err(ContractError::DeadlineNotPassed);
+        if proposal.status != Status::Active {
+            return Err(ContractError::AlreadyProcessed);
         }
 
-        // Only works for refundable states
-        if !matches!(launch.status, LaunchStatus::RefundAvailable | LaunchStatus::Failed | LaunchStatus::Cancelled) {
-            return Err(ContractError::invalid_state("Launch is not in refundable state"));
+        if current_block > proposal.voting_ends {
+            return Err(ContractError::DeadlinePassed);
         }
 
-        // Get contribution
-        let contribution = launch.contributions.remove(&user)
-            .ok_or(ContractError::invalid_state("No contribution to refund for this user"))?;
-
-        if contribution == 0 {
-            return Err(ContractError::ZeroAmount);
+        if proposal.voters.contains_key(&caller) {
+            return Err(ContractError::AlreadyExists);
         }
 
-        // Transfer refund to user
-        transfer_native(user, contribution)?;
+        let tally = match choice {
+            VoteChoice::For => &mut proposal.votes_for,
+            VoteChoice::Against => &mut proposal.votes_against,
+            VoteChoice::Abstain => &mut proposal.votes_abstain,
+        };
+        *tally = tally.saturating_add(weight);
+        proposal.voters.insert(caller, choice);
 
-        let _ = self.emit_event(LaunchpadEvent::AdminForceRefund {
+        let _ = self.emit_event(LaunchpadEvent::VoteCast {
             launch_id,
-            user,
-            amount: contribution,
+            proposal_id,
+            voter: caller,
+            choice,
+            weight,
         });
 
-        // Check if all refunds processed
-        if launch.contributions.is_empty() {
-            launch.refunds_processed = true;
-            launch.status = LaunchStatus::Finalized;
-            let _ = self.emit_event(LaunchpadEvent::LaunchFinalized { launch_id });
-        }
-
-        Ok(contribution)
+        Ok(weight)
     }
 
-    /// Return all deposited tokens to creator when launch fails.
+    /// Settle a proposal after voting ends, applying its action if it passed.
+    ///
+    /// Returns whether the proposal passed.
     #[export(unwrap_result)]
-    pub async fn return_tokens_on_failure(&mut self, launch_id: Id) -> Result<Amount, ContractError> {
-        let s = storage_mut();
+    pub fn execute_proposal(&mut self, launch_id: Id, proposal_id: u32) -> Result<bool, ContractError> {
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
         let caller = gstd::msg::source();
+        let current_block = gstd::exec::block_height();
 
         let launch = s.launches.get_mut(&launch_id)
             .ok_or(ContractError::NotFound)?;
 
-        // Only creator or owner can return tokens
-        if caller != launch.creator && caller != s.owner {
-            return Err(ContractError::Unauthorized);
+        let proposal = s.proposals
+            .get_mut(&launch_id)
+            .and_then(|p| p.get_mut(proposal_id as usize))
+            .ok_or(ContractError::NotFound)?;
+
+        if proposal.status != Status::Active {
+            return Err(ContractError::AlreadyProcessed);
         }
 
-        // Check if launch failed or was cancelled
-        if !matches!(launch.status, LaunchStatus::Failed | LaunchStatus::Cancelled | LaunchStatus::RefundAvailable) {
-            return Err(ContractError::invalid_state("Launch must be failed or cancelled"));
+        if current_block <= proposal.voting_ends {
+            return Err(ContractError::DeadlineNotPassed);
         }
 
-        // Check if tokens were deposited
-        if !launch.tokens_deposited {
-            return Err(ContractError::invalid_state("No tokens were deposited"));
+        let governance = launch.governance.as_ref()
+            .ok_or(ContractError::invalid_state("Governance not enabled"))?;
+
+        // A passed action that no longer applies counts as rejected
+        let passed = proposal.passed(governance, launch.total_raised)
+            && launch.ensure_proposal_action(&proposal.action).is_ok();
+
+        let (votes_for, votes_against, votes_abstain) =
+            (proposal.votes_for, proposal.votes_against, proposal.votes_abstain);
+
+        if !passed {
+            proposal.status = Status::Failed;
+
+            let _ = self.emit_event(LaunchpadEvent::ProposalRejected {
+                launch_id,
+                proposal_id,
+                votes_for,
+                votes_against,
+                votes_abstain,
+            });
+
+            return Ok(false);
         }
 
-        // Ensure refunds are processed first
-        if !launch.contributions.is_empty() {
-            return Err(ContractError::invalid_state("Refunds must be processed first"));
+        proposal.status = Status::Completed;
+
+        let _ = self.emit_event(LaunchpadEvent::ProposalExecuted {
+            launch_id,
+            proposal_id,
+            votes_for,
+            votes_against,
+            votes_abstain,
+        });
+
+        match proposal.action {
+            ProposalAction::CancelLaunch => self.apply_cancellation(launch, caller),
+            ProposalAction::ExtendEndTime(new_end_time) => {
+                let old_end_time = launch.extend_end_time(new_end_time.saturating_sub(launch.end_time));
+
+                let _ = self.emit_event(LaunchpadEvent::EndTimeExtended {
+                    launch_id,
+                    old_end_time,
+                    new_end_time: launch.end_time,
+                });
+            }
+            ProposalAction::ApproveMilestone(index) => {
+                let tranche = launch.complete_milestone(index);
+
+                let _ = self.emit_event(LaunchpadEvent::MilestoneCompleted {
+                    launch_id,
+                    index,
+                    tranche,
+                });
+            }
         }
 
-        // Return all tokens to creator
-        VftClient::transfer(
-            launch.token_address,
-            launch.creator,
-            U256::from(launch.total_tokens),
-        ).await?;
-
-        // Update state
-        launch.tokens_deposited = false;
-        
-        self.emit_event(LaunchpadEvent::TokensClaimed {
-            launch_id,
-            user: launch.creator,
-            amount: launch.total_tokens,
-        });
-
-        Ok(launch.total_tokens)
+        Ok(true)
     }
 
     // -------------------------------------------------------------------------
@@ -1313,45 +5176,79 @@ impl LaunchpadService {
     // -------------------------------------------------------------------------
 
     /// Withdraw raised funds (creator only, after success).
+    ///
+    /// Launches with milestones pay out the tranches of completed milestones.
     #[export(unwrap_result)]
-    pub fn withdraw_funds(&mut self, launch_id: Id) -> Result<Amount, ContractError> {
-        let s = storage_mut();
+    pub async fn withdraw_funds(&mut self, launch_id: Id) -> Result<Amount, ContractError> {
         let caller = gstd::msg::source();
 
-        let launch = s.launches.get_mut(&launch_id)
-            .ok_or(ContractError::NotFound)?;
+        // Validate and mark as withdrawn; storage is released before the await
+        let (payment_token, released, amount_to_creator, fee) = {
+            let mut storage = self.storage_mut();
+            let s = &mut *storage;
 
-        if caller != launch.creator {
-            return Err(ContractError::Unauthorized);
-        }
+            let launch = s.launches.get_mut(&launch_id)
+                .ok_or(ContractError::NotFound)?;
 
-        // Check status - must be successful
-        if !matches!(launch.status, LaunchStatus::DistributionPending | LaunchStatus::Succeeded) {
-            return Err(ContractError::invalid_state("Launch not successful"));
-        }
+            if caller != launch.creator {
+                return Err(ContractError::Unauthorized);
+            }
 
-        if launch.funds_withdrawn {
-            return Err(ContractError::AlreadyProcessed);
-        }
+            // Check status - must be successful
+            if !launch.is_successful() {
+                return Err(ContractError::invalid_state("Launch not successful"));
+            }
 
-        let total = launch.total_raised;
+            if launch.funds_withdrawn {
+                return Err(ContractError::AlreadyProcessed);
+            }
 
-        // Calculate platform fee
-        let fee = total
-            .saturating_mul(s.fee_basis_points as u128)
-            .checked_div(10_000)
-            .unwrap_or(0);
+            let total = launch.releasable_funds();
+            if total == 0 {
+                return Err(ContractError::invalid_state("No milestone funds unlocked"));
+            }
+
+            // Calculate platform fee
+            let fee = total
+                .saturating_mul(s.fee_basis_points as u128)
+                .checked_div(10_000)
+                .unwrap_or(0);
+
+            let amount_to_creator = total.saturating_sub(fee);
+
+            // Update state FIRST
+            launch.funds_released = launch.funds_released.saturating_add(total);
+            launch.funds_withdrawn = launch.funds_released >= launch.creator_funds();
 
-        let amount_to_creator = total.saturating_sub(fee);
+            (launch.payment_token, total, amount_to_creator, fee)
+        };
+
+        // Transfer to creator in the launch currency
+        let transfer_result = self.transfer_payment(launch_id, payment_token, caller, caller, amount_to_creator).await;
+
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
+
+        if transfer_result.is_err() {
+            // Allow the withdrawal to be retried (returning Err would discard this, see contribute_vft)
+            if let Some(launch) = s.launches.get_mut(&launch_id) {
+                launch.funds_released = launch.funds_released.saturating_sub(released);
+                launch.funds_withdrawn = false;
+            }
+
+            let _ = self.emit_event(LaunchpadEvent::TokenTransferFailed {
+                launch_id,
+                user: caller,
+                amount: amount_to_creator,
+                reason: String::from("Funds transfer failed"),
+            });
 
-        // Update state FIRST
-        launch.funds_withdrawn = true;
-        s.accumulated_fees = s.accumulated_fees.saturating_add(fee);
+            return Ok(0);
+        }
 
-        // Transfer to creator
-        transfer_native(caller, amount_to_creator)?;
+        s.accrue_fee(payment_token, fee);
 
-        self.emit_event(LaunchpadEvent::FundsWithdrawn {
+        let _ = self.emit_event(LaunchpadEvent::FundsWithdrawn {
             launch_id,
             creator: caller,
             amount: amount_to_creator,
@@ -1361,15 +5258,14 @@ impl LaunchpadService {
         Ok(amount_to_creator)
     }
 
-    /// Withdraw accumulated platform fees (owner only, sent to fee_recipient).
+    /// Withdraw accumulated platform fees (FEE_MANAGER role, sent to fee_recipient).
     #[export(unwrap_result)]
     pub fn withdraw_fees(&mut self) -> Result<Amount, ContractError> {
-        let s = storage_mut();
+        let mut storage = self.storage_mut();
+        let s = &mut *storage;
         let caller = gstd::msg::source();
 
-        if caller != s.owner {
-            return Err(ContractError::Unauthorized);
-        }
+        s.ensure_role(roles::FEE_MANAGER, &caller)?;
 
         let available = s.accumulated_fees.saturating_sub(s.fees_withdrawn);
         if available == 0 {
@@ -1381,9 +5277,9 @@ impl LaunchpadService {
         let recipient = s.fee_recipient;
 
         // Transfer to fee recipient (may differ from owner)
-        transfer_native(recipient, available)?;
+        s.send_native(recipient, recipient, available)?;
 
-        self.emit_event(LaunchpadEvent::FeesWithdrawn {
+        let _ = self.emit_event(LaunchpadEvent::FeesWithdrawn {
             owner: recipient,
             amount: available,
             total_accumulated: s.accumulated_fees,
@@ -1392,33 +5288,85 @@ impl LaunchpadService {
         Ok(available)
     }
 
-    /// Rescue tokens accidentally sent to contract (owner only).
-    /// Cannot rescue sale tokens from any launch.
+    /// Withdraw accumulated platform fees in a VFT payment token (FEE_MANAGER role, sent to fee_recipient).
+    ///
+    /// A rejected transfer stays in the ledger for `retry_transfer` and 0 is returned.
+    #[export(unwrap_result)]
+    pub async fn withdraw_token_fees(&mut self, token_address: ActorId) -> Result<Amount, ContractError> {
+        let _guard = ReentrancyGuard::start(self.storage)?;
+        let caller = gstd::msg::source();
+
+        // Validate and reserve the fees; storage is released before the await
+        let (recipient, available) = {
+            let mut storage = self.storage_mut();
+            let s = &mut *storage;
+
+            s.ensure_role(roles::FEE_MANAGER, &caller)?;
+
+            let available = s.available_token_fees(&token_address);
+            if available == 0 {
+                return Err(ContractError::ZeroAmount);
+            }
+
+            // Update state first
+            let withdrawn = s.token_fees_withdrawn.entry(token_address).or_insert(0);
+            *withdrawn = withdrawn.saturating_add(available);
+
+            (s.fee_recipient, available)
+        };
+
+        // Transfer to fee recipient (may differ from owner); a rejected
+        // transfer stays in the ledger, so the fees remain reserved for it
+        if self.send_tracked(None, token_address, recipient, available).await.is_err() {
+            return Ok(0);
+        }
+
+        let total_accumulated = self.storage().token_fees.get(&token_address).copied().unwrap_or(0);
+        let _ = self.emit_event(LaunchpadEvent::TokenFeesWithdrawn {
+            token_address,
+            recipient,
+            amount: available,
+            total_accumulated,
+        });
+
+        Ok(available)
+    }
+
+    /// Rescue tokens accidentally sent to contract (admin only).
+    /// Cannot rescue sale tokens or payment tokens from any launch.
     #[export(unwrap_result)]
     pub async fn rescue_tokens(&mut self, token_address: ActorId, amount: U256) -> Result<(), ContractError> {
-        let _guard = ReentrancyGuard::start()?;
+        let _guard = ReentrancyGuard::start(self.storage)?;
 
-        let s = storage_mut();
         let caller = gstd::msg::source();
 
-        if caller != s.owner {
-            return Err(ContractError::Unauthorized);
-        }
+        // Validate; storage is released before the await
+        let recipient = {
+            let s = self.storage();
 
-        if amount == 0 {
-            return Err(ContractError::ZeroAmount);
-        }
+            s.ensure_role(RoleId::DEFAULT_ADMIN, &caller)?;
 
-        // Ensure the token is not from any active launch
-        for launch in s.launches.values() {
-            if launch.token_address == token_address {
-                return Err(ContractError::invalid_state("Cannot rescue sale tokens"));
+            if amount == 0 {
+                return Err(ContractError::ZeroAmount);
             }
-        }
 
-        // Transfer tokens to fee recipient
-        let recipient = s.fee_recipient;
-        VftClient::transfer(token_address, recipient, amount).await?;
+            // Ensure the token is not from any active launch
+            for launch in s.launches.values() {
+                if launch.token_address == token_address {
+                    return Err(ContractError::invalid_state("Cannot rescue sale tokens"));
+                }
+                if launch.payment_token == TokenType::Vft(token_address) {
+                    return Err(ContractError::invalid_state("Cannot rescue payment tokens"));
+                }
+            }
+
+            s.fee_recipient
+        };
+
+        // Transfer tokens to fee recipient (a rejected transfer stays in the ledger)
+        if self.send_tracked(None, token_address, recipient, amount).await.is_err() {
+            return Ok(());
+        }
 
         let _ = self.emit_event(LaunchpadEvent::TokensRescued {
             token_address,
@@ -1429,6 +5377,159 @@ impl LaunchpadService {
         Ok(())
     }
 
+    /// Collect native payouts that bounced earlier, sent to the caller.
+    ///
+    /// The payout is tracked like any other; if it bounces again the
+    /// balance becomes withdrawable again.
+    #[export(unwrap_result)]
+    pub fn withdraw_pending(&mut self) -> Result<Amount, ContractError> {
+        let mut storage = self.storage_mut();
+        let caller = gstd::msg::source();
+
+        let amount = storage.pending_withdrawals.remove(&caller)
+            .ok_or(ContractError::ZeroAmount)?;
+
+        storage.send_native(caller, caller, amount)?;
+
+        let _ = self.emit_event(LaunchpadEvent::PendingWithdrawn {
+            user: caller,
+            amount,
+        });
+
+        Ok(amount)
+    }
+
+    // -------------------------------------------------------------------------
+    // PAYOUTS
+    // -------------------------------------------------------------------------
+
+    /// Transfer `amount` of a launch's payment currency owed to `user` to `to`.
+    ///
+    /// Payouts are tracked and only fail before anything is sent: bounced
+    /// native value is kept for `user`'s `withdraw_pending`, and a rejected
+    /// VFT transfer stays in the ledger for `retry_transfer`.
+    async fn transfer_payment(
+        &mut self,
+        launch_id: Id,
+        payment_token: TokenType,
+        user: ActorId,
+        to: ActorId,
+        amount: Amount,
+    ) -> Result<(), ContractError> {
+        match payment_token {
+            TokenType::Native => self.storage_mut().send_native(user, to, amount),
+            TokenType::Vft(token_address) => {
+                if amount > 0 {
+                    // The payout is settled either way; the ledger records the outcome
+                    let _ = self.send_tracked(Some(launch_id), token_address, to, amount).await;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Credit a bounced native payout (called from the program's reply handler).
+    fn handle_native_reply(&mut self) {
+        let bounced = self.storage_mut().settle_native_reply();
+        if let Some((user, to, amount)) = bounced {
+            let _ = self.emit_event(LaunchpadEvent::NativePayoutFailed {
+                user,
+                to,
+                amount,
+            });
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // TRANSFER LEDGER
+    // -------------------------------------------------------------------------
+
+    /// Record a VFT transfer in the ledger, then send it.
+    ///
+    /// The entry is committed before the await and removed once the token
+    /// confirms. A rejected transfer stays `Failed` and emits `TransferFailed`;
+    /// its ID is returned as the error.
+    async fn send_tracked(&mut self, launch_id: Option<Id>, token: ActorId, to: ActorId, amount: Amount) -> Result<(), Id> {
+        let transfer_id = {
+            let mut storage = self.storage_mut();
+            let transfer_id = storage.next_transfer_id;
+            storage.next_transfer_id = transfer_id.saturating_add(1);
+            storage.pending_transfers.insert(transfer_id, PendingTransfer {
+                launch_id,
+                token,
+                to,
+                amount,
+                status: TransferStatus::Pending,
+                attempts: 1,
+                created_at: gstd::exec::block_height(),
+            });
+            transfer_id
+        };
+
+        self.settle_transfer(transfer_id, token, to, amount).await
+    }
+
+    /// Await a ledger transfer and record the outcome.
+    async fn settle_transfer(&mut self, transfer_id: Id, token: ActorId, to: ActorId, amount: Amount) -> Result<(), Id> {
+        let result = VftClient::transfer(token, to, U256::from(amount)).await;
+
+        let mut storage = self.storage_mut();
+        if result.is_ok() {
+            storage.pending_transfers.remove(&transfer_id);
+            return Ok(());
+        }
+
+        if let Some(transfer) = storage.pending_transfers.get_mut(&transfer_id) {
+            transfer.status = TransferStatus::Failed;
+        }
+        drop(storage);
+
+        let _ = self.emit_event(LaunchpadEvent::TransferFailed {
+            transfer_id,
+            token,
+            to,
+            amount,
+        });
+
+        Err(transfer_id)
+    }
+
+    /// Re-send a failed ledger transfer to its original recipient (anyone can call).
+    ///
+    /// Returns whether it went through; a rejected retry stays `Failed`.
+    #[export(unwrap_result)]
+    pub async fn retry_transfer(&mut self, transfer_id: Id) -> Result<bool, ContractError> {
+        let _guard = ReentrancyGuard::start(self.storage)?;
+
+        // Mark in flight; storage is released before the await
+        let (token, to, amount) = {
+            let mut storage = self.storage_mut();
+
+            let transfer = storage.pending_transfers.get_mut(&transfer_id)
+                .ok_or(ContractError::NotFound)?;
+
+            if transfer.status != TransferStatus::Failed {
+                return Err(ContractError::invalid_state("Transfer is not failed"));
+            }
+
+            transfer.status = TransferStatus::Pending;
+            transfer.attempts = transfer.attempts.saturating_add(1);
+            (transfer.token, transfer.to, transfer.amount)
+        };
+
+        if self.settle_transfer(transfer_id, token, to, amount).await.is_err() {
+            return Ok(false);
+        }
+
+        let _ = self.emit_event(LaunchpadEvent::TransferRetried {
+            transfer_id,
+            to,
+            amount,
+        });
+
+        Ok(true)
+    }
+
     // -------------------------------------------------------------------------
     // QUERIES
     // -------------------------------------------------------------------------
@@ -1436,13 +5537,32 @@ impl LaunchpadService {
     /// Get launch by ID.
     #[export]
     pub fn get_launch(&self, launch_id: Id) -> Option<Launch> {
-        storage().launches.get(&launch_id).cloned()
+        self.storage().launches.get(&launch_id).cloned()
+    }
+
+    /// Get a governance proposal.
+    #[export]
+    pub fn get_proposal(&self, launch_id: Id, proposal_id: u32) -> Option<Proposal> {
+        self.storage()
+            .proposals
+            .get(&launch_id)
+            .and_then(|p| p.get(proposal_id as usize).cloned())
+    }
+
+    /// Get all governance proposals of a launch.
+    #[export]
+    pub fn get_proposals(&self, launch_id: Id) -> Vec<Proposal> {
+        self.storage()
+            .proposals
+            .get(&launch_id)
+            .cloned()
+            .unwrap_or_default()
     }
 
     /// Get all launches by creator.
     #[export]
     pub fn get_creator_launches(&self, creator: ActorId) -> Vec<Launch> {
-        storage()
+        self.storage()
             .launches
             .values()
             .filter(|l| l.creator == creator)
@@ -1453,7 +5573,7 @@ impl LaunchpadService {
     /// Get active launches.
     #[export]
     pub fn get_active_launches(&self) -> Vec<Launch> {
-        storage()
+        self.storage()
             .launches
             .values()
             .filter(|l| l.status == LaunchStatus::Active)
@@ -1464,7 +5584,7 @@ impl LaunchpadService {
     /// Get user's contribution to a launch.
     #[export]
     pub fn get_contribution(&self, launch_id: Id, user: ActorId) -> Amount {
-        storage()
+        self.storage()
             .launches
             .get(&launch_id)
             .and_then(|l| l.contributions.get(&user).copied())
@@ -1474,7 +5594,7 @@ impl LaunchpadService {
     /// Get user's tokens purchased in a launch.
     #[export]
     pub fn get_tokens_purchased(&self, launch_id: Id, user: ActorId) -> Amount {
-        storage()
+        self.storage()
             .launches
             .get(&launch_id)
             .and_then(|l| l.tokens_purchased.get(&user).copied())
@@ -1484,71 +5604,194 @@ impl LaunchpadService {
     /// Get user's claimed tokens.
     #[export]
     pub fn get_claimed(&self, launch_id: Id, user: ActorId) -> Amount {
-        storage()
+        self.storage()
             .launches
             .get(&launch_id)
             .and_then(|l| l.claimed.get(&user).copied())
             .unwrap_or(0)
     }
 
-    /// Check if address is whitelisted.
+    /// Get the live price per token (the clearing price once an auction settles).
+    #[export]
+    pub fn get_current_price(&self, launch_id: Id) -> Option<Amount> {
+        let current_block = gstd::exec::block_height();
+        self.storage().launches.get(&launch_id).map(|l| l.price_at(current_block))
+    }
+
+    /// Get the payment owed back to a contributor after settlement.
+    #[export]
+    pub fn get_excess_refund(&self, launch_id: Id, user: ActorId) -> Amount {
+        self.storage()
+            .launches
+            .get(&launch_id)
+            .and_then(|l| l.excess_refunds.get(&user).copied())
+            .unwrap_or(0)
+    }
+
+    /// Get the sale phase running now, with its index.
+    #[export]
+    pub fn get_active_phase(&self, launch_id: Id) -> Option<(u32, SalePhase)> {
+        let current_block = gstd::exec::block_height();
+        self.storage()
+            .launches
+            .get(&launch_id)
+            .and_then(|l| l.current_phase(current_block).map(|(index, phase)| (index, phase.clone())))
+    }
+
+    /// Get how much an address contributed during a sale phase.
+    #[export]
+    pub fn get_phase_contribution(&self, launch_id: Id, phase: u32, user: ActorId) -> Amount {
+        self.storage()
+            .launches
+            .get(&launch_id)
+            .and_then(|l| l.phase_contributions.get(&(phase, user)).copied())
+            .unwrap_or(0)
+    }
+
+    /// Check a Merkle whitelist proof for an address.
+    #[export]
+    pub fn verify_whitelist_proof(&self, launch_id: Id, address: ActorId, proof: MerkleProof) -> bool {
+        self.storage()
+            .launches
+            .get(&launch_id)
+            .is_some_and(|l| l.wallet_cap(&address, Some(&proof)).is_ok())
+    }
+
+    /// Check if address is whitelisted (always false for Merkle whitelists,
+    /// use `verify_whitelist_proof` for those).
     #[export]
     pub fn is_whitelisted(&self, launch_id: Id, address: ActorId) -> bool {
-        storage()
+        self.storage()
             .launches
             .get(&launch_id)
-            .map(|l| !l.whitelist_enabled || l.whitelist.contains(&address))
+            .map(|l| l.whitelist_root.is_none() && l.can_participate(&address))
             .unwrap_or(false)
     }
 
     /// Get total number of launches.
     #[export]
     pub fn get_launch_count(&self) -> u64 {
-        storage().next_launch_id
+        self.storage().next_launch_id
     }
 
     /// Get accumulated platform fees.
     #[export]
     pub fn get_accumulated_fees(&self) -> Amount {
-        storage().accumulated_fees
+        self.storage().accumulated_fees
     }
 
     /// Get available fees to withdraw.
     #[export]
     pub fn get_available_fees(&self) -> Amount {
-        let s = storage();
+        let s = self.storage();
         s.accumulated_fees.saturating_sub(s.fees_withdrawn)
     }
 
+    /// Get available fees to withdraw in a VFT payment token.
+    #[export]
+    pub fn get_available_token_fees(&self, token_address: ActorId) -> Amount {
+        self.storage().available_token_fees(&token_address)
+    }
+
     /// Get platform owner.
     #[export]
     pub fn get_owner(&self) -> ActorId {
-        storage().owner
+        self.storage().owner
+    }
+
+    /// Get the proposed owner, if a transfer is pending.
+    #[export]
+    pub fn get_pending_owner(&self) -> Option<ActorId> {
+        self.storage().pending_owner
+    }
+
+    /// Check if an account holds a role.
+    #[export]
+    pub fn has_role(&self, role: RoleId, account: ActorId) -> bool {
+        self.storage().has_role(role, &account)
+    }
+
+    /// Get all accounts holding a role.
+    #[export]
+    pub fn get_role_members(&self, role: RoleId) -> Vec<ActorId> {
+        self.storage()
+            .roles
+            .get(&role)
+            .map(|members| members.iter().copied().collect())
+            .unwrap_or_default()
     }
 
     /// Get fee recipient address.
     #[export]
     pub fn get_fee_recipient(&self) -> ActorId {
-        storage().fee_recipient
+        self.storage().fee_recipient
     }
 
     /// Check if contract is paused.
     #[export]
     pub fn is_paused(&self) -> bool {
-        storage().paused
+        self.storage().paused
+    }
+
+    /// Get the DEX used for liquidity seeding.
+    #[export]
+    pub fn get_dex(&self) -> Option<ActorId> {
+        self.storage().dex
+    }
+
+    /// Get the shortest liquidity lock new launches may configure.
+    #[export]
+    pub fn get_min_liquidity_lock(&self) -> BlockNumber {
+        self.storage().min_liquidity_lock
+    }
+
+    /// Get how long after `end_time` liquidity can be seeded.
+    #[export]
+    pub fn get_liquidity_seeding_period(&self) -> BlockNumber {
+        self.storage().liquidity_seeding_period
+    }
+
+    /// Get the liquidity lock of a launch and whether it has expired.
+    #[export]
+    pub fn get_liquidity_lock(&self, launch_id: Id) -> Option<(LiquidityLock, bool)> {
+        let current_block = gstd::exec::block_height();
+        self.storage()
+            .liquidity_locks
+            .get(&launch_id)
+            .map(|lock| (lock.clone(), lock.is_unlocked(current_block)))
     }
 
     /// Get gas configuration for program deployment.
     #[export]
     pub fn get_gas_config(&self) -> (u64, u64) {
-        let s = storage();
+        let s = self.storage();
         (s.gas_for_program, s.gas_for_reply)
     }
 
+    /// Get the reply deposit for each native payout.
+    #[export]
+    pub fn get_native_reply_gas(&self) -> u64 {
+        self.storage().gas_for_native_reply
+    }
+
+    /// Get the gas reserved for and sent with scheduled messages.
+    #[export]
+    pub fn get_automation_gas(&self) -> u64 {
+        self.storage().gas_for_automation
+    }
+
+    /// Get a launch's next automated step and the gas still reserved for it.
+    #[export]
+    pub fn get_schedule(&self, launch_id: Id) -> Option<(LaunchSchedule, u64)> {
+        let s = self.storage();
+        let reserved = s.reservations.get(&launch_id).map_or(0, |reservation| reservation.amount());
+        s.schedules.get(&launch_id).map(|schedule| (schedule.clone(), reserved))
+    }
+
     /// Get claimable tokens for a user (accounting for vesting).
     #[export]
     pub fn get_claimable_tokens(&self, launch_id: Id, user: ActorId) -> Amount {
-        let s = storage();
+        let s = self.storage();
         let current_block = gstd::exec::block_height();
 
         let launch = match s.launches.get(&launch_id) {
@@ -1556,27 +5799,52 @@ impl LaunchpadService {
             None => return 0,
         };
 
-        let total_purchased = launch.tokens_purchased.get(&user).copied().unwrap_or(0);
-        if total_purchased == 0 {
-            return 0;
-        }
+        launch.claimable_tokens(&user, current_block)
+    }
 
-        let claimable = if let Some(ref vesting) = launch.vesting_config {
-            let vested = calculate_vested_tokens(total_purchased, vesting, current_block);
-            let already_claimed = launch.claimed.get(&user).copied().unwrap_or(0);
-            vested.saturating_sub(already_claimed)
-        } else {
-            let already_claimed = launch.claimed.get(&user).copied().unwrap_or(0);
-            total_purchased.saturating_sub(already_claimed)
-        };
+    /// Get the address a user's payouts are redirected to, if registered.
+    #[export]
+    pub fn get_claim_address(&self, user: ActorId) -> Option<ActorId> {
+        self.storage().claim_addresses.get(&user).copied()
+    }
+
+    /// Get bounced native payouts waiting for `withdraw_pending`.
+    #[export]
+    pub fn get_pending_withdrawal(&self, user: ActorId) -> Amount {
+        self.storage().pending_withdrawals.get(&user).copied().unwrap_or(0)
+    }
+
+    /// Get an unsettled ledger transfer by ID.
+    #[export]
+    pub fn get_pending_transfer(&self, transfer_id: Id) -> Option<PendingTransfer> {
+        self.storage().pending_transfers.get(&transfer_id).cloned()
+    }
+
+    /// Get failed ledger transfers awaiting `retry_transfer`, by ID.
+    #[export]
+    pub fn get_stuck_transfers(&self) -> Vec<(Id, PendingTransfer)> {
+        self.storage()
+            .pending_transfers
+            .iter()
+            .filter(|(_, transfer)| transfer.status == TransferStatus::Failed)
+            .map(|(id, transfer)| (*id, transfer.clone()))
+            .collect()
+    }
 
-        claimable
+    /// Get vested team tokens a beneficiary can claim now.
+    #[export]
+    pub fn get_claimable_team_tokens(&self, launch_id: Id, beneficiary: ActorId) -> Amount {
+        let current_block = gstd::exec::block_height();
+        self.storage()
+            .launches
+            .get(&launch_id)
+            .map_or(0, |launch| launch.claimable_team_tokens(&beneficiary, current_block))
     }
 
     /// Get all contributors for a launch.
     #[export]
     pub fn get_contributors(&self, launch_id: Id) -> Vec<ActorId> {
-        storage()
+        self.storage()
             .launches
             .get(&launch_id)
             .map(|l| l.contributors.clone())
@@ -1608,13 +5876,15 @@ impl LaunchpadService {
     /// Get launch token details for external systems.
     #[export]
     pub fn get_launch_token_info(&self, launch_id: Id) -> Option<LaunchTokenInfo> {
-        let launch = storage().launches.get(&launch_id)?;
-        
+        let storage = self.storage();
+        let launch = storage.launches.get(&launch_id)?;
+        let current_block = gstd::exec::block_height();
+
         Some(LaunchTokenInfo {
             token_address: launch.token_address,
-            total_supply: launch.total_tokens,
+            total_supply: launch.token_supply(),
             circulating_supply: launch.total_tokens - launch.tokens_remaining,
-            price_per_token: launch.price_per_token,
+            price_per_token: launch.price_at(current_block),
             launch_ended: matches!(
                 launch.status, 
                 LaunchStatus::Ended | 
@@ -1629,7 +5899,8 @@ impl LaunchpadService {
     /// Get all token holders and balances for bridge systems.
     #[export]
     pub fn get_token_holders(&self, launch_id: Id) -> Vec<TokenHolder> {
-        let launch = match storage().launches.get(&launch_id) {
+        let storage = self.storage();
+        let launch = match storage.launches.get(&launch_id) {
             Some(l) => l,
             None => return Vec::new(),
         };
@@ -1651,41 +5922,50 @@ impl LaunchpadService {
     }
 
     /// Return unsold tokens to creator after launch finalization.
+    ///
+    /// Returns 0 if the token rejects the transfer; it then stays in the
+    /// transfer ledger for `retry_transfer`.
     #[export(unwrap_result)]
     pub async fn return_unsold_tokens(&mut self, launch_id: Id) -> Result<Amount, ContractError> {
-        let s = storage_mut();
+        let _guard = ReentrancyGuard::start(self.storage)?;
+
         let caller = gstd::msg::source();
 
-        let launch = s.launches.get_mut(&launch_id)
-            .ok_or(ContractError::NotFound)?;
+        // Validate and settle state; storage is released before the await
+        let (token_address, unsold) = {
+            let mut storage = self.storage_mut();
 
-        if caller != launch.creator {
-            return Err(ContractError::Unauthorized);
-        }
+            let launch = storage.launches.get_mut(&launch_id)
+                .ok_or(ContractError::NotFound)?;
 
-        // Check if launch has ended and funds were withdrawn
-        if !launch.funds_withdrawn {
-            return Err(ContractError::invalid_state("Must withdraw funds first"));
-        }
+            if caller != launch.creator {
+                return Err(ContractError::Unauthorized);
+            }
 
-        // Check if there are unsold tokens
-        if launch.tokens_remaining == 0 {
-            return Err(ContractError::invalid_state("No unsold tokens to return"));
-        }
+            // Check if launch has ended and funds were withdrawn
+            if !launch.funds_withdrawn {
+                return Err(ContractError::invalid_state("Must withdraw funds first"));
+            }
 
-        let unsold = launch.tokens_remaining;
-        
-        // Transfer unsold tokens back to creator
-        VftClient::transfer(
-            launch.token_address,
-            caller,
-            U256::from(unsold),
-        ).await?;
+            // Unsold tokens plus whatever the liquidity pool did not need
+            let unused_reserve = launch.unused_liquidity_reserve();
+            let unsold = launch.tokens_remaining.saturating_add(unused_reserve);
+            if unsold == 0 {
+                return Err(ContractError::invalid_state("No unsold tokens to return"));
+            }
 
-        // Update state
-        launch.tokens_remaining = 0;
+            launch.unsold_returned = launch.unsold_returned.saturating_add(launch.tokens_remaining);
+            launch.tokens_remaining = 0;
+            launch.liquidity_reserve = launch.liquidity_reserve.saturating_sub(unused_reserve);
+            (launch.token_address, unsold)
+        };
+
+        // Transfer unsold tokens back to creator
+        if self.send_tracked(Some(launch_id), token_address, caller, unsold).await.is_err() {
+            return Ok(0);
+        }
 
-        self.emit_event(LaunchpadEvent::TokensClaimed {
+        let _ = self.emit_event(LaunchpadEvent::TokensClaimed {
             launch_id,
             user: caller,
             amount: unsold,
@@ -1700,27 +5980,43 @@ impl LaunchpadService {
 // =============================================================================
 
 /// Launchpad Program entry point.
+///
+/// Owns the single [`LaunchpadStorage`] instance for the lifetime of the program.
 #[derive(Default)]
-pub struct LaunchpadProgram(());
+pub struct LaunchpadProgram {
+    storage: RefCell<LaunchpadStorage>,
+}
 
 #[sails_rs::program]
 impl LaunchpadProgram {
     /// Initialize with default 2% fee.
     pub fn new() -> Self {
         let owner = gstd::msg::source();
-        init_storage(owner, 200); // 2% fee (200 basis points)
-        Self(())
+        Self {
+            storage: RefCell::new(LaunchpadStorage::new(owner, 200)), // 2% fee (200 basis points)
+        }
     }
 
     /// Initialize with custom fee.
     pub fn new_with_fee(fee_basis_points: u16) -> Self {
         let owner = gstd::msg::source();
-        init_storage(owner, fee_basis_points);
-        Self(())
+        Self {
+            storage: RefCell::new(LaunchpadStorage::new(owner, fee_basis_points)),
+        }
     }
 
     /// Get the launchpad service.
-    pub fn launchpad(&self) -> LaunchpadService {
-        LaunchpadService::new()
+    pub fn launchpad(&self) -> LaunchpadService<'_> {
+        LaunchpadService::new(&self.storage)
+    }
+
+    /// Settle tracked native payouts and scheduled steps when their replies arrive.
+    #[handle_reply]
+    // Only the wasm entry point calls it
+    #[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+    fn handle_reply(&self) {
+        let mut launchpad = self.launchpad();
+        launchpad.handle_native_reply();
+        launchpad.handle_automation_reply();
     }
 }
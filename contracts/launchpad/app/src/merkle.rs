@@ -0,0 +1,49 @@
+//! Merkle allowlists - Inclusion proofs for large whitelists.
+//!
+//! A launch stores only the 32-byte root. Each leaf commits to an address
+//! and its allocation cap (0 = the launch's `max_per_wallet`):
+//! `leaf = blake2b_256(SCALE(account, allocation))`.
+//! Inner nodes hash the sorted pair of children, so proofs need no indices.
+
+use alloc::vec::Vec;
+use blake2::{digest::consts::U32, Blake2b, Digest};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sails_rs::prelude::*;
+use vara_contracts_shared::Amount;
+
+/// 32-byte Merkle hash.
+pub type Hash = [u8; 32];
+
+type Blake2b256 = Blake2b<U32>;
+
+/// Proof that an address belongs to a launch's Merkle allowlist.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub struct MerkleProof {
+    /// Allocation cap committed in the leaf (0 = no per-address cap).
+    pub allocation: Amount,
+    /// Sibling hashes from the leaf up to the root.
+    pub proof: Vec<Hash>,
+}
+
+/// Compute the leaf for `account` with `allocation`.
+pub fn leaf(account: &ActorId, allocation: Amount) -> Hash {
+    Blake2b256::digest((account, allocation).encode()).into()
+}
+
+/// Hash two sibling nodes in sorted order.
+pub fn hash_pair(a: &Hash, b: &Hash) -> Hash {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut hasher = Blake2b256::new();
+    hasher.update(first);
+    hasher.update(second);
+    hasher.finalize().into()
+}
+
+/// Check that `leaf` is included under `root`.
+pub fn verify(root: &Hash, leaf: Hash, proof: &[Hash]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| hash_pair(&node, sibling));
+    computed == *root
+}
@@ -146,6 +146,18 @@ impl VftClient {
         ).await
     }
     
+    /// Burn tokens held by `from`.
+    pub async fn burn(
+        token_address: ActorId,
+        from: ActorId,
+        amount: U256,
+    ) -> Result<(), ContractError> {
+        Self::send_action(
+            token_address,
+            VftAction::Burn { from, value: amount },
+        ).await
+    }
+    
     /// Approve another account to spend tokens.
     pub async fn approve(
         token_address: ActorId,
@@ -226,14 +238,10 @@ impl VftClient {
 // HELPER FUNCTIONS
 // =============================================================================
 
-/// Check if the launchpad contract has sufficient token balance.
-pub async fn verify_token_balance(
-    token_address: ActorId,
-    required_amount: U256,
-) -> Result<bool, ContractError> {
+/// Token balance held by the launchpad contract.
+pub async fn launchpad_token_balance(token_address: ActorId) -> Result<U256, ContractError> {
     let contract_address = gstd::exec::program_id();
-    let balance = VftClient::balance_of(token_address, contract_address).await?;
-    Ok(balance >= required_amount)
+    VftClient::balance_of(token_address, contract_address).await
 }
 
 /// Check if creator has approved launchpad to transfer tokens.
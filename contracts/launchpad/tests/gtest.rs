@@ -1,9 +1,17 @@
 //! Integration tests for Launchpad v2 contract.
 
-use gtest::{Program, System};
-use launchpad_app::{CreateLaunchInput, CONTRACT_NAME, CONTRACT_VERSION};
-use sails_rs::prelude::ActorId;
-use sails_rs::Encode;
+use gtest::{Log, Program, System};
+use launchpad_app::{
+    merkle::{self, MerkleProof},
+    roles, AntiSnipingConfig, BondingCurve, CreateLaunchInput, CurveKind, DecayCurve, DutchAuction, GovernanceConfig, Launch,
+    LaunchSchedule, LaunchStatus, LiquidityConfig, LiquidityLock, PendingTransfer, PhaseEligibility, Proposal, ProposalAction, SalePhase,
+    ScheduledAction, TeamAllocation, TeamShare, TransferStatus, CONTRACT_NAME, CONTRACT_VERSION,
+};
+use mock_amm::DexQuery;
+use mock_vft::{VftAction, VftInitParams, VftQuery};
+use sails_rs::prelude::{ActorId, CodeId};
+use sails_rs::{Decode, Encode};
+use vara_contracts_shared::{Milestone, RoleId, Status, TokenType, VestingConfig, VestingCurve, VoteChoice};
 
 // User IDs must be >= 100 to be valid in gtest
 const OWNER: u64 = 100;
@@ -13,12 +21,18 @@ const CONTRIBUTOR2: u64 = 103;
 const NON_WHITELISTED: u64 = 104;
 const ANYONE: u64 = 105;
 
+// Fixed program ID for the launchpad so tests can query token balances held by it
+const LAUNCHPAD: u64 = 1;
+// Fixed program ID for the stablecoin used by VFT-denominated launches
+const STABLECOIN: u64 = 2;
+// Fixed program ID for a creator-supplied sale token
+const PROJECT_TOKEN: u64 = 3;
+// Fixed program ID for the stand-in AMM that receives launch liquidity
+const AMM: u64 = 4;
+
 const ONE_VARA: u128 = 1_000_000_000_000; // 10^12
 const EXISTENTIAL_DEPOSIT: u128 = 10 * ONE_VARA;
 
-// Token address (dummy for testing)
-const TOKEN_ADDRESS: u64 = 200;
-
 /// Encode a Sails constructor name
 fn encode_constructor(name: &str) -> Vec<u8> {
     name.encode()
@@ -41,6 +55,113 @@ fn encode_call_no_params(service: &str, method: &str) -> Vec<u8> {
     payload
 }
 
+/// Call a Launchpad query and decode its reply (strips the service/method prefix)
+fn query<P: Encode, R: Decode>(system: &System, program: &Program<'_>, method: &str, params: P) -> R {
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", method, params));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "{method} query should succeed");
+
+    let reply = result
+        .log()
+        .iter()
+        .find(|log| log.reply_to() == Some(msg_id))
+        .expect("Query reply not found");
+    let mut payload = reply.payload();
+    let _service = String::decode(&mut payload).expect("Invalid service route");
+    let _method = String::decode(&mut payload).expect("Invalid method route");
+    R::decode(&mut payload).expect("Failed to decode query reply")
+}
+
+/// Query a VFT balance directly from the token program
+fn token_balance(system: &System, token_address: ActorId, account: u64) -> u128 {
+    let token = system.get_program(token_address).expect("Token program not found");
+    let msg_id = token.send_bytes(ANYONE, VftQuery::BalanceOf { account: account.into() }.encode());
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "BalanceOf query should succeed");
+
+    let reply = result
+        .log()
+        .iter()
+        .find(|log| log.reply_to() == Some(msg_id))
+        .expect("BalanceOf reply not found");
+    u128::decode(&mut reply.payload()).expect("Failed to decode balance")
+}
+
+/// Send a raw VFT action to a token program, returning whether it succeeded
+fn token_action(system: &System, token_address: ActorId, from: u64, action: VftAction) -> bool {
+    let token = system.get_program(token_address).expect("Token program not found");
+    let msg_id = token.send_bytes(from, action.encode());
+    let result = system.run_next_block();
+    result.succeed.contains(&msg_id)
+}
+
+/// Deploy a mock stablecoin with `supply` minted to each of `holders`
+fn deploy_stablecoin(system: &System, holders: &[u64], supply: u128) -> ActorId {
+    deploy_mock_token(system, STABLECOIN, "USD", holders, supply)
+}
+
+/// Deploy a mock VFT at `id` with `supply` minted to each of `holders`
+fn deploy_mock_token(system: &System, id: u64, symbol: &str, holders: &[u64], supply: u128) -> ActorId {
+    let token = Program::from_binary_with_id(system, id, mock_vft::WASM_BINARY);
+    let init = VftInitParams {
+        name: symbol.into(),
+        symbol: symbol.into(),
+        decimals: 12,
+        total_supply: 0,
+        initial_owner: ActorId::from(OWNER),
+    };
+    let msg_id = token.send_bytes(OWNER, init.encode());
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Token init should succeed");
+
+    for holder in holders {
+        let minted = token_action(
+            system,
+            token.id(),
+            OWNER,
+            VftAction::Mint { to: ActorId::from(*holder), value: supply },
+        );
+        assert!(minted, "Token mint should succeed");
+    }
+
+    token.id()
+}
+
+/// Deploy the stand-in AMM
+fn deploy_amm(system: &System) -> ActorId {
+    let amm = Program::from_binary_with_id(system, AMM, mock_amm::WASM_BINARY);
+    let msg_id = amm.send_bytes(OWNER, Vec::new());
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "AMM init should succeed");
+    amm.id()
+}
+
+/// Query the pool shares `account` holds in the AMM's `(token, quote)` pool
+fn amm_liquidity(system: &System, token: ActorId, quote: Option<ActorId>, account: u64) -> u128 {
+    let amm = system.get_program(AMM).expect("AMM program not found");
+    let msg_id = amm.send_bytes(ANYONE, DexQuery::LiquidityOf { token, quote, account: account.into() }.encode());
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "LiquidityOf query should succeed");
+
+    let reply = result
+        .log()
+        .iter()
+        .find(|log| log.reply_to() == Some(msg_id))
+        .expect("LiquidityOf reply not found");
+    u128::decode(&mut reply.payload()).expect("Failed to decode liquidity")
+}
+
+/// Approve the launchpad to pull `value` of `token_address` from `owner`
+fn approve_launchpad(system: &System, token_address: ActorId, owner: u64, value: u128) {
+    let approved = token_action(
+        system,
+        token_address,
+        owner,
+        VftAction::Approve { spender: ActorId::from(LAUNCHPAD), value },
+    );
+    assert!(approved, "Approve should succeed");
+}
+
 fn setup_system() -> System {
     let system = System::new();
     system.init_logger();
@@ -57,10 +178,7 @@ fn setup_system() -> System {
 }
 
 fn deploy_contract(system: &System) -> Program<'_> {
-    let program = Program::from_file(
-        system,
-        "../../target/wasm32-gear/release/launchpad.opt.wasm",
-    );
+    let program = Program::from_binary_with_id(system, LAUNCHPAD, vara_launchpad::WASM_BINARY);
 
     // Initialize with OWNER as the deployer (default 2% fee)
     let init_payload = encode_constructor("New");
@@ -71,8 +189,23 @@ fn deploy_contract(system: &System) -> Program<'_> {
         panic!("Contract init failed. Check WASM file exists and is valid.");
     }
 
-    // Note: In production, owner would need to call set_vft_code_id()
-    // before creating launches. Tests mock this behavior.
+    // Token deployment charges the existential deposit to the launchpad
+    system.transfer(OWNER, program.id(), EXISTENTIAL_DEPOSIT * 10, true);
+
+    // Register the mock VFT code so launches can deploy their tokens
+    let vft_code_id: CodeId = system.submit_code(mock_vft::WASM_BINARY);
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "SetVftCodeId", vft_code_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "SetVftCodeId should succeed");
+
+    // The unoptimized debug WASM costs more to load than the default reply
+    // deposits cover
+    let msg_id = program.send_bytes(
+        OWNER,
+        encode_call("Launchpad", "SetGasConfig", (10_000_000_000u64, 8_000_000_000u64)),
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "SetGasConfig should succeed");
 
     program
 }
@@ -85,6 +218,7 @@ fn create_test_launch_input(system: &System) -> CreateLaunchInput {
         // Token creation parameters
         token_name: "Test Token".into(),
         token_symbol: "TEST".into(),
+        token_address: None,
         
         // Launch parameters
         title: "Test Token Launch".into(),
@@ -96,11 +230,42 @@ fn create_test_launch_input(system: &System) -> CreateLaunchInput {
         max_per_wallet: 200 * ONE_VARA,
         start_time: current_block + 10,
         end_time: current_block + 10000,
+        anti_sniping: None,
+        soft_cap_extension: 0,
         whitelist_enabled: false,
+        whitelist_root: None,
+        phases: Vec::new(),
+        auction: None,
+        pro_rata: false,
+        bonding_curve: None,
+        liquidity: None,
         vesting_config: None,
+        positions_transferable: false,
+        team_allocation: None,
+        payment_token: TokenType::Native,
+        milestones: Vec::new(),
+        governance: None,
     }
 }
 
+/// Approve and contribute `amount` of a VFT payment token
+fn contribute_vft(
+    system: &System,
+    program: &Program<'_>,
+    token_address: ActorId,
+    contributor: u64,
+    launch_id: u64,
+    amount: u128,
+) {
+    approve_launchpad(system, token_address, contributor, amount);
+    let msg_id = program.send_bytes(
+        contributor,
+        encode_call("Launchpad", "ContributeVft", (launch_id, amount)),
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ContributeVft should succeed");
+}
+
 /// Advance blocks to simulate time passing
 fn advance_blocks(system: &System, count: u32) {
     for _ in 0..count {
@@ -108,6 +273,27 @@ fn advance_blocks(system: &System, count: u32) {
     }
 }
 
+/// Advance until the chain reaches `block`
+fn advance_to_block(system: &System, block: u32) {
+    advance_blocks(system, block.saturating_sub(system.block_height()));
+}
+
+/// Create and start a launch, then advance into its contribution window
+fn setup_active_launch(system: &System, program: &Program<'_>, input: CreateLaunchInput) -> u64 {
+    let launch_id: u64 = query(system, program, "GetLaunchCount", ());
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", input));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "CreateLaunch should succeed");
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "StartLaunch", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "StartLaunch should succeed");
+
+    advance_blocks(system, 15);
+    launch_id
+}
+
 // =============================================================================
 // BASIC TESTS
 // =============================================================================
@@ -516,22 +702,36 @@ fn test_query_launch() {
     let program = deploy_contract(&system);
 
     let input = create_test_launch_input(&system);
-    program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", input));
+    program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", input.clone()));
     system.run_next_block();
 
     let launch_id: u64 = 0;
 
     // Query launch
-    let msg_id = program.send_bytes(
-        ANYONE,
-        encode_call("Launchpad", "GetLaunch", launch_id),
-    );
-    let result = system.run_next_block();
-
-    assert!(
-        result.succeed.contains(&msg_id),
-        "GetLaunch query should succeed"
-    );
+    let launch: Option<Launch> = query(&system, &program, "GetLaunch", launch_id);
+    let launch = launch.expect("GetLaunch should return the created launch");
+    assert_eq!(launch.id, launch_id);
+    assert_eq!(launch.creator, ActorId::from(CREATOR));
+    assert_eq!(launch.title, input.title);
+    assert_eq!(launch.total_tokens, input.total_tokens);
+    assert_eq!(launch.tokens_remaining, input.total_tokens);
+    assert_eq!(launch.status, LaunchStatus::Pending);
+    assert_ne!(launch.token_address, ActorId::zero());
+
+    let count: u64 = query(&system, &program, "GetLaunchCount", ());
+    assert_eq!(count, 1);
+
+    let creator_launches: Vec<Launch> =
+        query(&system, &program, "GetCreatorLaunches", ActorId::from(CREATOR));
+    assert_eq!(creator_launches.len(), 1);
+
+    let other_launches: Vec<Launch> =
+        query(&system, &program, "GetCreatorLaunches", ActorId::from(ANYONE));
+    assert!(other_launches.is_empty());
+
+    // Deployed token holds the full supply on behalf of the launchpad
+    let balance = token_balance(&system, launch.token_address, LAUNCHPAD);
+    assert_eq!(balance, input.total_tokens);
 }
 
 #[test]
@@ -544,21 +744,134 @@ fn test_query_active_launches() {
     program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", input));
     system.run_next_block();
 
+    let active: Vec<Launch> = query(&system, &program, "GetActiveLaunches", ());
+    assert!(active.is_empty(), "Pending launch should not be active");
+
     let launch_id: u64 = 0;
     program.send_bytes(CREATOR, encode_call("Launchpad", "StartLaunch", launch_id));
     system.run_next_block();
 
     // Query active launches
-    let msg_id = program.send_bytes(
-        ANYONE,
-        encode_call_no_params("Launchpad", "GetActiveLaunches"),
+    let active: Vec<Launch> = query(&system, &program, "GetActiveLaunches", ());
+    assert_eq!(active.len(), 1);
+    assert_eq!(active[0].id, launch_id);
+    assert_eq!(active[0].status, LaunchStatus::Active);
+}
+
+#[test]
+fn test_query_contributions() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    let input = create_test_launch_input(&system);
+    let price = input.price_per_token;
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    let msg_id = program.send_bytes_with_value(
+        CONTRIBUTOR1,
+        encode_call("Launchpad", "Contribute", launch_id),
+        50 * ONE_VARA,
     );
     let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id));
 
-    assert!(
-        result.succeed.contains(&msg_id),
-        "GetActiveLaunches query should succeed"
+    let contribution: u128 = query(
+        &system,
+        &program,
+        "GetContribution",
+        (launch_id, ActorId::from(CONTRIBUTOR1)),
+    );
+    assert_eq!(contribution, 50 * ONE_VARA);
+
+    let purchased: u128 = query(
+        &system,
+        &program,
+        "GetTokensPurchased",
+        (launch_id, ActorId::from(CONTRIBUTOR1)),
+    );
+    assert_eq!(purchased, 50 * ONE_VARA / price);
+
+    let contributors: Vec<ActorId> = query(&system, &program, "GetContributors", launch_id);
+    assert_eq!(contributors, vec![ActorId::from(CONTRIBUTOR1)]);
+
+    let launch: Option<Launch> = query(&system, &program, "GetLaunch", launch_id);
+    let launch = launch.unwrap();
+    assert_eq!(launch.total_raised, 50 * ONE_VARA);
+    assert_eq!(launch.tokens_remaining, launch.total_tokens - purchased);
+}
+
+#[test]
+fn test_query_whitelist() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    let mut input = create_test_launch_input(&system);
+    input.whitelist_enabled = true;
+    program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", input));
+    system.run_next_block();
+
+    let launch_id: u64 = 0;
+    let addresses: Vec<ActorId> = vec![ActorId::from(CONTRIBUTOR1)];
+    program.send_bytes(
+        CREATOR,
+        encode_call("Launchpad", "AddToWhitelist", (launch_id, addresses)),
+    );
+    system.run_next_block();
+
+    let listed: bool = query(
+        &system,
+        &program,
+        "IsWhitelisted",
+        (launch_id, ActorId::from(CONTRIBUTOR1)),
+    );
+    assert!(listed);
+
+    let listed: bool = query(
+        &system,
+        &program,
+        "IsWhitelisted",
+        (launch_id, ActorId::from(NON_WHITELISTED)),
+    );
+    assert!(!listed);
+}
+
+#[test]
+fn test_query_admin_config() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    let owner: ActorId = query(&system, &program, "GetOwner", ());
+    assert_eq!(owner, ActorId::from(OWNER));
+
+    let paused: bool = query(&system, &program, "IsPaused", ());
+    assert!(!paused);
+
+    program.send_bytes(OWNER, encode_call_no_params("Launchpad", "Pause"));
+    system.run_next_block();
+    let paused: bool = query(&system, &program, "IsPaused", ());
+    assert!(paused);
+
+    let recipient: ActorId = query(&system, &program, "GetFeeRecipient", ());
+    assert_eq!(recipient, ActorId::from(OWNER));
+
+    program.send_bytes(
+        OWNER,
+        encode_call("Launchpad", "SetFeeRecipient", ActorId::from(ANYONE)),
     );
+    system.run_next_block();
+    let recipient: ActorId = query(&system, &program, "GetFeeRecipient", ());
+    assert_eq!(recipient, ActorId::from(ANYONE));
+
+    let gas_config = (20_000_000_000u64, 6_000_000_000u64);
+    program.send_bytes(OWNER, encode_call("Launchpad", "SetGasConfig", gas_config));
+    system.run_next_block();
+    let stored: (u64, u64) = query(&system, &program, "GetGasConfig", ());
+    assert_eq!(stored, gas_config);
+
+    let name: String = query(&system, &program, "GetContractName", ());
+    assert_eq!(name, CONTRACT_NAME);
+    let version: String = query(&system, &program, "GetContractVersion", ());
+    assert_eq!(version, CONTRACT_VERSION);
 }
 
 #[test]
@@ -703,3 +1016,2661 @@ fn test_withdraw_platform_fees() {
         "WithdrawFees should succeed for owner"
     );
 }
+
+#[test]
+fn test_query_fees_and_claims() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    let mut input = create_test_launch_input(&system);
+    input.min_raise = 50 * ONE_VARA;
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    program.send_bytes_with_value(
+        CONTRIBUTOR1,
+        encode_call("Launchpad", "Contribute", launch_id),
+        100 * ONE_VARA,
+    );
+    system.run_next_block();
+
+    advance_blocks(&system, 10000);
+    program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    system.run_next_block();
+
+    let launch: Option<Launch> = query(&system, &program, "GetLaunch", launch_id);
+    let launch = launch.unwrap();
+    assert_eq!(launch.status, LaunchStatus::DistributionPending);
+
+    // Withdraw funds accrues the 2% platform fee
+    program.send_bytes(CREATOR, encode_call("Launchpad", "WithdrawFunds", launch_id));
+    system.run_next_block();
+
+    let accumulated: u128 = query(&system, &program, "GetAccumulatedFees", ());
+    assert_eq!(accumulated, 2 * ONE_VARA);
+    let available: u128 = query(&system, &program, "GetAvailableFees", ());
+    assert_eq!(available, 2 * ONE_VARA);
+
+    program.send_bytes(OWNER, encode_call_no_params("Launchpad", "WithdrawFees"));
+    system.run_next_block();
+
+    let available: u128 = query(&system, &program, "GetAvailableFees", ());
+    assert_eq!(available, 0);
+
+    // Claim tokens and check both launchpad accounting and the token balance
+    let purchased: u128 = query(
+        &system,
+        &program,
+        "GetTokensPurchased",
+        (launch_id, ActorId::from(CONTRIBUTOR1)),
+    );
+    let claimable: u128 = query(
+        &system,
+        &program,
+        "GetClaimableTokens",
+        (launch_id, ActorId::from(CONTRIBUTOR1)),
+    );
+    assert_eq!(claimable, purchased);
+
+    let msg_id = program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "ClaimTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ClaimTokens should succeed");
+
+    let claimed: u128 = query(
+        &system,
+        &program,
+        "GetClaimed",
+        (launch_id, ActorId::from(CONTRIBUTOR1)),
+    );
+    assert_eq!(claimed, purchased);
+    assert_eq!(token_balance(&system, launch.token_address, CONTRIBUTOR1), purchased);
+}
+
+// =============================================================================
+// VFT PAYMENT TOKEN TESTS
+// =============================================================================
+
+#[test]
+fn test_vft_launch_lifecycle() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let stablecoin = deploy_stablecoin(&system, &[CONTRIBUTOR1, CONTRIBUTOR2], 1000 * ONE_VARA);
+
+    let mut input = create_test_launch_input(&system);
+    input.min_raise = 50 * ONE_VARA;
+    input.payment_token = TokenType::Vft(stablecoin);
+    let price = input.price_per_token;
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    let launch: Option<Launch> = query(&system, &program, "GetLaunch", launch_id);
+    assert_eq!(launch.unwrap().payment_token, TokenType::Vft(stablecoin));
+
+    // Native contributions are rejected for VFT launches
+    let msg_id = program.send_bytes_with_value(
+        CONTRIBUTOR1,
+        encode_call("Launchpad", "Contribute", launch_id),
+        50 * ONE_VARA,
+    );
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Native contribution should fail");
+
+    // Contributions are pulled from approved allowances
+    approve_launchpad(&system, stablecoin, CONTRIBUTOR1, 60 * ONE_VARA);
+    approve_launchpad(&system, stablecoin, CONTRIBUTOR2, 40 * ONE_VARA);
+
+    let msg_id = program.send_bytes(
+        CONTRIBUTOR1,
+        encode_call("Launchpad", "ContributeVft", (launch_id, 60 * ONE_VARA)),
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "VFT contribution 1 should succeed");
+
+    let msg_id = program.send_bytes(
+        CONTRIBUTOR2,
+        encode_call("Launchpad", "ContributeVft", (launch_id, 40 * ONE_VARA)),
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "VFT contribution 2 should succeed");
+
+    assert_eq!(token_balance(&system, stablecoin, LAUNCHPAD), 100 * ONE_VARA);
+    assert_eq!(token_balance(&system, stablecoin, CONTRIBUTOR1), 940 * ONE_VARA);
+
+    let purchased: u128 = query(
+        &system,
+        &program,
+        "GetTokensPurchased",
+        (launch_id, ActorId::from(CONTRIBUTOR1)),
+    );
+    assert_eq!(purchased, 60 * ONE_VARA / price);
+
+    advance_blocks(&system, 10000);
+    program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    system.run_next_block();
+
+    // Creator is paid in the stablecoin, fee is kept per currency
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "WithdrawFunds", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "WithdrawFunds should succeed");
+
+    assert_eq!(token_balance(&system, stablecoin, CREATOR), 98 * ONE_VARA);
+
+    let native_fees: u128 = query(&system, &program, "GetAvailableFees", ());
+    assert_eq!(native_fees, 0);
+    let token_fees: u128 = query(&system, &program, "GetAvailableTokenFees", stablecoin);
+    assert_eq!(token_fees, 2 * ONE_VARA);
+
+    let msg_id = program.send_bytes(
+        ANYONE,
+        encode_call("Launchpad", "WithdrawTokenFees", stablecoin),
+    );
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Only owner can withdraw token fees");
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "WithdrawTokenFees", stablecoin));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "WithdrawTokenFees should succeed");
+
+    assert_eq!(token_balance(&system, stablecoin, OWNER), 2 * ONE_VARA);
+    let token_fees: u128 = query(&system, &program, "GetAvailableTokenFees", stablecoin);
+    assert_eq!(token_fees, 0);
+
+    // Payment tokens held for launches cannot be rescued
+    let msg_id = program.send_bytes(
+        OWNER,
+        encode_call("Launchpad", "RescueTokens", (stablecoin, ONE_VARA)),
+    );
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Rescuing payment tokens should fail");
+}
+
+#[test]
+fn test_vft_refund() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let stablecoin = deploy_stablecoin(&system, &[CONTRIBUTOR1], 1000 * ONE_VARA);
+
+    let mut input = create_test_launch_input(&system);
+    input.min_raise = 500 * ONE_VARA;
+    input.payment_token = TokenType::Vft(stablecoin);
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    approve_launchpad(&system, stablecoin, CONTRIBUTOR1, 50 * ONE_VARA);
+    program.send_bytes(
+        CONTRIBUTOR1,
+        encode_call("Launchpad", "ContributeVft", (launch_id, 50 * ONE_VARA)),
+    );
+    system.run_next_block();
+    assert_eq!(token_balance(&system, stablecoin, CONTRIBUTOR1), 950 * ONE_VARA);
+
+    advance_blocks(&system, 10000);
+    program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    system.run_next_block();
+
+    let msg_id = program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "ClaimRefund", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ClaimRefund should succeed");
+
+    assert_eq!(token_balance(&system, stablecoin, CONTRIBUTOR1), 1000 * ONE_VARA);
+
+    let launch: Option<Launch> = query(&system, &program, "GetLaunch", launch_id);
+    assert_eq!(launch.unwrap().status, LaunchStatus::Finalized);
+}
+
+#[test]
+fn test_vft_contribution_without_approval_is_rolled_back() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let stablecoin = deploy_stablecoin(&system, &[CONTRIBUTOR1], 1000 * ONE_VARA);
+
+    let mut input = create_test_launch_input(&system);
+    input.payment_token = TokenType::Vft(stablecoin);
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    // The pull fails, the reservation is released and nothing is purchased
+    let msg_id = program.send_bytes(
+        CONTRIBUTOR1,
+        encode_call("Launchpad", "ContributeVft", (launch_id, 50 * ONE_VARA)),
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id));
+
+    let contribution: u128 = query(
+        &system,
+        &program,
+        "GetContribution",
+        (launch_id, ActorId::from(CONTRIBUTOR1)),
+    );
+    assert_eq!(contribution, 0, "Failed pull must not leave a contribution behind");
+
+    let contributors: Vec<ActorId> = query(&system, &program, "GetContributors", launch_id);
+    assert!(contributors.is_empty());
+}
+
+// =============================================================================
+// BRING-YOUR-OWN-TOKEN TESTS
+// =============================================================================
+
+#[test]
+fn test_existing_token_launch_requires_deposit() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    let mut input = create_test_launch_input(&system);
+    let total_tokens = input.total_tokens;
+    let token = deploy_mock_token(&system, PROJECT_TOKEN, "PRJ", &[CREATOR], total_tokens);
+    input.token_address = Some(token);
+
+    let launch_id: u64 = query(&system, &program, "GetLaunchCount", ());
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", input));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "CreateLaunch should succeed");
+
+    let launch: Option<Launch> = query(&system, &program, "GetLaunch", launch_id);
+    let launch = launch.unwrap();
+    assert_eq!(launch.token_address, token);
+    assert!(!launch.tokens_deposited);
+
+    // Cannot start before the tokens are in the contract
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "StartLaunch", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "StartLaunch should fail before deposit");
+
+    // Deposit needs an approval covering total_tokens
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "DepositTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "DepositTokens should fail without approval");
+
+    approve_launchpad(&system, token, CREATOR, total_tokens);
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "DepositTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Only creator can deposit");
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "DepositTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "DepositTokens should succeed");
+
+    assert_eq!(token_balance(&system, token, LAUNCHPAD), total_tokens);
+    assert_eq!(token_balance(&system, token, CREATOR), 0);
+
+    let launch: Option<Launch> = query(&system, &program, "GetLaunch", launch_id);
+    assert!(launch.unwrap().tokens_deposited);
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "DepositTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Second deposit should fail");
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "StartLaunch", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "StartLaunch should succeed after deposit");
+}
+
+#[test]
+fn test_deposit_counts_only_transferred_tokens() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    let mut input = create_test_launch_input(&system);
+    let total_tokens = input.total_tokens;
+    let token = deploy_mock_token(&system, PROJECT_TOKEN, "PRJ", &[CREATOR], total_tokens);
+    input.token_address = Some(token);
+
+    let launch_id: u64 = query(&system, &program, "GetLaunchCount", ());
+    program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", input));
+    system.run_next_block();
+
+    // Half is sent directly, so `transfer_from` cannot move the full amount
+    let sent = token_action(
+        &system,
+        token,
+        CREATOR,
+        VftAction::Transfer { to: ActorId::from(LAUNCHPAD), value: total_tokens / 2 },
+    );
+    assert!(sent, "Transfer should succeed");
+    approve_launchpad(&system, token, CREATOR, total_tokens);
+
+    // The failed transfer is reported rather than reverted
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "DepositTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "DepositTokens should report the failure");
+
+    let launch: Option<Launch> = query(&system, &program, "GetLaunch", launch_id);
+    assert!(!launch.unwrap().tokens_deposited);
+    assert_eq!(token_balance(&system, token, LAUNCHPAD), total_tokens / 2);
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "StartLaunch", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Tokens held beforehand do not count as deposited");
+}
+
+#[test]
+fn test_existing_token_returned_after_cancel() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    let mut input = create_test_launch_input(&system);
+    let total_tokens = input.total_tokens;
+    let token = deploy_mock_token(&system, PROJECT_TOKEN, "PRJ", &[CREATOR], total_tokens);
+    input.token_address = Some(token);
+
+    let launch_id: u64 = query(&system, &program, "GetLaunchCount", ());
+    program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", input));
+    system.run_next_block();
+
+    approve_launchpad(&system, token, CREATOR, total_tokens);
+    program.send_bytes(CREATOR, encode_call("Launchpad", "DepositTokens", launch_id));
+    system.run_next_block();
+    assert_eq!(token_balance(&system, token, LAUNCHPAD), total_tokens);
+
+    program.send_bytes(CREATOR, encode_call("Launchpad", "CancelLaunch", launch_id));
+    system.run_next_block();
+
+    let msg_id = program.send_bytes(
+        CREATOR,
+        encode_call("Launchpad", "ReturnTokensOnFailure", launch_id),
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ReturnTokensOnFailure should succeed");
+
+    assert_eq!(token_balance(&system, token, CREATOR), total_tokens);
+    assert_eq!(token_balance(&system, token, LAUNCHPAD), 0);
+}
+
+// =============================================================================
+// MILESTONE TESTS
+// =============================================================================
+
+/// Successful stablecoin launch with a 60/40 milestone split:
+/// CONTRIBUTOR1 puts in 60, CONTRIBUTOR2 puts in 40.
+fn setup_milestone_launch(system: &System, program: &Program<'_>) -> (u64, ActorId) {
+    let stablecoin = deploy_stablecoin(system, &[CONTRIBUTOR1, CONTRIBUTOR2], 1000 * ONE_VARA);
+
+    let mut input = create_test_launch_input(system);
+    input.min_raise = 50 * ONE_VARA;
+    input.payment_token = TokenType::Vft(stablecoin);
+    input.milestones = vec![
+        Milestone::new("MVP".into(), 6_000),
+        Milestone::new("Mainnet".into(), 4_000),
+    ];
+    input.governance = Some(test_governance_config());
+    let launch_id = setup_active_launch(system, program, input);
+
+    contribute_vft(system, program, stablecoin, CONTRIBUTOR1, launch_id, 60 * ONE_VARA);
+    contribute_vft(system, program, stablecoin, CONTRIBUTOR2, launch_id, 40 * ONE_VARA);
+
+    advance_blocks(system, 10000);
+    program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    system.run_next_block();
+
+    (launch_id, stablecoin)
+}
+
+#[test]
+fn test_milestone_rejects_bad_tranches() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    let mut input = create_test_launch_input(&system);
+    input.milestones = vec![
+        Milestone::new("MVP".into(), 6_000),
+        Milestone::new("Mainnet".into(), 3_000),
+    ];
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", input));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Tranches not summing to 100% should fail");
+}
+
+#[test]
+fn test_milestone_release_by_owner() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let (launch_id, stablecoin) = setup_milestone_launch(&system, &program);
+
+    // Nothing is unlocked before the first milestone
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "WithdrawFunds", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "WithdrawFunds should fail before milestones");
+
+    // Milestones complete in order, only by the owner
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "CompleteMilestone", (launch_id, 1u32)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Out-of-order milestone should fail");
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "CompleteMilestone", (launch_id, 0u32)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Creator cannot complete milestones");
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "CompleteMilestone", (launch_id, 0u32)));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "CompleteMilestone should succeed");
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "WithdrawFunds", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "First tranche withdrawal should succeed");
+
+    // 60% of 100, minus the 2% fee
+    assert_eq!(token_balance(&system, stablecoin, CREATOR), 588 * ONE_VARA / 10);
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "WithdrawFunds", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Tranche cannot be withdrawn twice");
+
+    program.send_bytes(OWNER, encode_call("Launchpad", "CompleteMilestone", (launch_id, 1u32)));
+    system.run_next_block();
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "WithdrawFunds", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Second tranche withdrawal should succeed");
+
+    assert_eq!(token_balance(&system, stablecoin, CREATOR), 98 * ONE_VARA);
+    let token_fees: u128 = query(&system, &program, "GetAvailableTokenFees", stablecoin);
+    assert_eq!(token_fees, 2 * ONE_VARA);
+
+    let launch: Option<Launch> = query(&system, &program, "GetLaunch", launch_id);
+    let launch = launch.unwrap();
+    assert!(launch.funds_withdrawn);
+    assert_eq!(launch.funds_released, 100 * ONE_VARA);
+}
+
+#[test]
+fn test_milestone_contributor_approval() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let (launch_id, _) = setup_milestone_launch(&system, &program);
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "ApproveMilestone", (launch_id, 0u32)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Non-contributors cannot approve");
+
+    // 40% of the raise is not a majority
+    program.send_bytes(CONTRIBUTOR2, encode_call("Launchpad", "ApproveMilestone", (launch_id, 0u32)));
+    system.run_next_block();
+
+    let launch: Option<Launch> = query(&system, &program, "GetLaunch", launch_id);
+    assert!(!launch.unwrap().milestones[0].completed);
+
+    let msg_id = program.send_bytes(CONTRIBUTOR2, encode_call("Launchpad", "ApproveMilestone", (launch_id, 0u32)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Double approval should fail");
+
+    program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "ApproveMilestone", (launch_id, 0u32)));
+    system.run_next_block();
+
+    let launch: Option<Launch> = query(&system, &program, "GetLaunch", launch_id);
+    let launch = launch.unwrap();
+    assert!(launch.milestones[0].completed);
+    assert!(!launch.milestones[1].completed);
+}
+
+#[test]
+fn test_abandoned_launch_refunds_unreleased_tranches() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let (launch_id, stablecoin) = setup_milestone_launch(&system, &program);
+
+    program.send_bytes(OWNER, encode_call("Launchpad", "CompleteMilestone", (launch_id, 0u32)));
+    system.run_next_block();
+    program.send_bytes(CREATOR, encode_call("Launchpad", "WithdrawFunds", launch_id));
+    system.run_next_block();
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "AbandonLaunch", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Only owner can abandon");
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "AbandonLaunch", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "AbandonLaunch should succeed");
+
+    // 40% of each contribution is still held
+    program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "ClaimRefund", launch_id));
+    system.run_next_block();
+    program.send_bytes(CONTRIBUTOR2, encode_call("Launchpad", "ClaimRefund", launch_id));
+    system.run_next_block();
+
+    assert_eq!(token_balance(&system, stablecoin, CONTRIBUTOR1), (940 + 24) * ONE_VARA);
+    assert_eq!(token_balance(&system, stablecoin, CONTRIBUTOR2), (960 + 16) * ONE_VARA);
+    assert_eq!(token_balance(&system, stablecoin, LAUNCHPAD), 12 * ONE_VARA / 10);
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "WithdrawFunds", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "No withdrawals after abandonment");
+}
+
+#[test]
+fn test_abandoned_launch_excludes_seeded_liquidity() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let stablecoin = deploy_stablecoin(&system, &[CONTRIBUTOR1], 1000 * ONE_VARA);
+    let amm = deploy_amm(&system);
+
+    program.send_bytes(OWNER, encode_call("Launchpad", "SetDex", Some(amm)));
+    system.run_next_block();
+
+    let mut input = create_test_launch_input(&system);
+    input.payment_token = TokenType::Vft(stablecoin);
+    input.milestones = vec![
+        Milestone::new("MVP".into(), 6_000),
+        Milestone::new("Mainnet".into(), 4_000),
+    ];
+    input.liquidity = Some(LiquidityConfig { raise_bps: 5000, lock_duration: 100 });
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR1, launch_id, 100 * ONE_VARA);
+    advance_blocks(&system, 10000);
+    program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    system.run_next_block();
+
+    // Half the raise went into the pool, so only the other half is refundable
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "AbandonLaunch", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "AbandonLaunch should succeed");
+    let reply = result
+        .log()
+        .iter()
+        .find(|log| log.reply_to() == Some(msg_id))
+        .expect("AbandonLaunch reply not found");
+    let (_, _, total_to_refund) = <(String, String, u128)>::decode(&mut reply.payload()).unwrap();
+    assert_eq!(total_to_refund, 50 * ONE_VARA);
+
+    let msg_id = program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "ClaimRefund", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ClaimRefund should succeed");
+    assert_eq!(token_balance(&system, stablecoin, CONTRIBUTOR1), (900 + 50) * ONE_VARA);
+    assert_eq!(token_balance(&system, stablecoin, LAUNCHPAD), 0);
+}
+
+#[test]
+fn test_abandoned_launch_returns_only_held_tokens() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let stablecoin = deploy_stablecoin(&system, &[CONTRIBUTOR1, CONTRIBUTOR2], 1000 * ONE_VARA);
+
+    let mut input = create_test_launch_input(&system);
+    input.min_raise = 50 * ONE_VARA;
+    input.payment_token = TokenType::Vft(stablecoin);
+    input.milestones = vec![
+        Milestone::new("MVP".into(), 6_000),
+        Milestone::new("Mainnet".into(), 4_000),
+    ];
+    input.team_allocation = Some(TeamAllocation {
+        total: 10_000,
+        vesting: VestingConfig::new(input.end_time, 0, 1),
+        beneficiaries: vec![TeamShare { beneficiary: ActorId::from(CREATOR), share_bps: 10_000 }],
+    });
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR1, launch_id, 60 * ONE_VARA);
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR2, launch_id, 40 * ONE_VARA);
+    advance_blocks(&system, 10000);
+    program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    system.run_next_block();
+
+    // Team and buyer tokens leave before the abandonment
+    for (sender, method) in [(CREATOR, "ClaimTeamTokens"), (CONTRIBUTOR1, "ClaimTokens")] {
+        let msg_id = program.send_bytes(sender, encode_call("Launchpad", method, launch_id));
+        let result = system.run_next_block();
+        assert!(result.succeed.contains(&msg_id), "{method} should succeed");
+    }
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "AbandonLaunch", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "AbandonLaunch should succeed");
+    for contributor in [CONTRIBUTOR1, CONTRIBUTOR2] {
+        let msg_id = program.send_bytes(contributor, encode_call("Launchpad", "ClaimRefund", launch_id));
+        let result = system.run_next_block();
+        assert!(result.succeed.contains(&msg_id), "ClaimRefund should succeed");
+    }
+
+    // Only what is still held goes back, so nothing is left stuck in the ledger
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "ReturnTokensOnFailure", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ReturnTokensOnFailure should succeed");
+
+    let stuck: Vec<(u64, PendingTransfer)> = query(&system, &program, "GetStuckTransfers", ());
+    assert!(stuck.is_empty());
+    assert_eq!(token_balance(&system, launch.token_address, LAUNCHPAD), 0);
+    assert_eq!(token_balance(&system, launch.token_address, CREATOR), launch.total_tokens - 60_000 + 10_000);
+}
+
+// =============================================================================
+// GOVERNANCE TESTS
+// =============================================================================
+
+/// Majority quorum and threshold with a short voting period
+fn test_governance_config() -> GovernanceConfig {
+    GovernanceConfig {
+        quorum_bps: 5_000,
+        approval_threshold_bps: 5_000,
+        voting_period: 100,
+    }
+}
+
+/// Active native launch with governance where CONTRIBUTOR1 holds 60% and CONTRIBUTOR2 40%
+fn setup_governed_launch(system: &System, program: &Program<'_>, governance: GovernanceConfig) -> u64 {
+    let mut input = create_test_launch_input(system);
+    input.governance = Some(governance);
+    let launch_id = setup_active_launch(system, program, input);
+
+    for (contributor, amount) in [(CONTRIBUTOR1, 60 * ONE_VARA), (CONTRIBUTOR2, 40 * ONE_VARA)] {
+        let msg_id = program.send_bytes_with_value(
+            contributor,
+            encode_call("Launchpad", "Contribute", launch_id),
+            amount,
+        );
+        let result = system.run_next_block();
+        assert!(result.succeed.contains(&msg_id), "Contribute should succeed");
+    }
+
+    launch_id
+}
+
+fn vote(system: &System, program: &Program<'_>, voter: u64, launch_id: u64, proposal_id: u32, choice: VoteChoice) {
+    let msg_id = program.send_bytes(voter, encode_call("Launchpad", "Vote", (launch_id, proposal_id, choice)));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Vote should succeed");
+}
+
+#[test]
+fn test_governance_cancels_live_launch() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let launch_id = setup_governed_launch(&system, &program, test_governance_config());
+
+    let msg_id = program.send_bytes(
+        ANYONE,
+        encode_call("Launchpad", "CreateProposal", (launch_id, ProposalAction::CancelLaunch)),
+    );
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Non-contributors cannot propose");
+
+    let msg_id = program.send_bytes(
+        CONTRIBUTOR2,
+        encode_call("Launchpad", "CreateProposal", (launch_id, ProposalAction::CancelLaunch)),
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "CreateProposal should succeed");
+
+    vote(&system, &program, CONTRIBUTOR1, launch_id, 0, VoteChoice::For);
+    vote(&system, &program, CONTRIBUTOR2, launch_id, 0, VoteChoice::Against);
+
+    let msg_id = program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "Vote", (launch_id, 0u32, VoteChoice::For)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Double vote should fail");
+
+    let proposal: Option<Proposal> = query(&system, &program, "GetProposal", (launch_id, 0u32));
+    let proposal = proposal.unwrap();
+    assert_eq!(proposal.votes_for, 60 * ONE_VARA);
+    assert_eq!(proposal.votes_against, 40 * ONE_VARA);
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "ExecuteProposal", (launch_id, 0u32)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Execution should wait for voting to end");
+
+    advance_blocks(&system, 100);
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "ExecuteProposal", (launch_id, 0u32)));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ExecuteProposal should succeed");
+
+    let launch: Option<Launch> = query(&system, &program, "GetLaunch", launch_id);
+    assert_eq!(launch.unwrap().status, LaunchStatus::RefundAvailable);
+
+    let proposal: Option<Proposal> = query(&system, &program, "GetProposal", (launch_id, 0u32));
+    assert_eq!(proposal.unwrap().status, Status::Completed);
+
+    let msg_id = program.send_bytes(CONTRIBUTOR2, encode_call("Launchpad", "ClaimRefund", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Refund should be claimable after cancellation");
+}
+
+#[test]
+fn test_governance_extends_end_time_only_with_quorum() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let governance = GovernanceConfig {
+        quorum_bps: 8_000,
+        ..test_governance_config()
+    };
+    let launch_id = setup_governed_launch(&system, &program, governance);
+
+    let launch: Option<Launch> = query(&system, &program, "GetLaunch", launch_id);
+    let end_time = launch.unwrap().end_time;
+    let extend = ProposalAction::ExtendEndTime(end_time + 500);
+
+    program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "CreateProposal", (launch_id, extend.clone())));
+    system.run_next_block();
+
+    // 60% turnout misses the 80% quorum
+    vote(&system, &program, CONTRIBUTOR1, launch_id, 0, VoteChoice::For);
+    advance_blocks(&system, 100);
+    program.send_bytes(ANYONE, encode_call("Launchpad", "ExecuteProposal", (launch_id, 0u32)));
+    system.run_next_block();
+
+    let proposal: Option<Proposal> = query(&system, &program, "GetProposal", (launch_id, 0u32));
+    assert_eq!(proposal.unwrap().status, Status::Failed);
+    let launch: Option<Launch> = query(&system, &program, "GetLaunch", launch_id);
+    assert_eq!(launch.unwrap().end_time, end_time);
+
+    // Full turnout with an abstention still passes on for/against weight
+    program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "CreateProposal", (launch_id, extend)));
+    system.run_next_block();
+    vote(&system, &program, CONTRIBUTOR1, launch_id, 1, VoteChoice::For);
+    vote(&system, &program, CONTRIBUTOR2, launch_id, 1, VoteChoice::Abstain);
+    advance_blocks(&system, 100);
+    program.send_bytes(ANYONE, encode_call("Launchpad", "ExecuteProposal", (launch_id, 1u32)));
+    system.run_next_block();
+
+    let launch: Option<Launch> = query(&system, &program, "GetLaunch", launch_id);
+    assert_eq!(launch.unwrap().end_time, end_time + 500);
+
+    let proposals: Vec<Proposal> = query(&system, &program, "GetProposals", launch_id);
+    assert_eq!(proposals.len(), 2);
+    assert_eq!(proposals[1].status, Status::Completed);
+}
+
+#[test]
+fn test_governance_cannot_extend_auction_end_time() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    let mut input = create_test_launch_input(&system);
+    input.governance = Some(test_governance_config());
+    input.auction = Some(DutchAuction {
+        start_price: 2 * ONE_VARA / 1000,
+        floor_price: ONE_VARA / 1000,
+        curve: DecayCurve::Linear,
+    });
+    let end_time = input.end_time;
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    let msg_id = program.send_bytes_with_value(
+        CONTRIBUTOR1,
+        encode_call("Launchpad", "Contribute", launch_id),
+        10 * ONE_VARA,
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Contribute should succeed");
+
+    // The decay curve is tied to the original end time
+    let extend = ProposalAction::ExtendEndTime(end_time + 500);
+    let msg_id = program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "CreateProposal", (launch_id, extend)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Extending an auction should fail");
+
+    let proposals: Vec<Proposal> = query(&system, &program, "GetProposals", launch_id);
+    assert!(proposals.is_empty());
+}
+
+#[test]
+fn test_late_contributions_and_soft_cap_extend_end_time() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    let mut input = create_test_launch_input(&system);
+    input.anti_sniping = Some(AntiSnipingConfig { window: 20, extension: 30, max_extension: 50 });
+    input.soft_cap_extension = 100;
+    let end = input.end_time;
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    let end_time = |system: &System| -> u32 {
+        query::<_, Option<Launch>>(system, &program, "GetLaunch", launch_id).unwrap().end_time
+    };
+    let contribute = |system: &System| {
+        let msg_id = program.send_bytes_with_value(
+            CONTRIBUTOR1,
+            encode_call("Launchpad", "Contribute", launch_id),
+            10 * ONE_VARA,
+        );
+        let result = system.run_next_block();
+        assert!(result.succeed.contains(&msg_id), "Contribute should succeed");
+    };
+
+    // Outside the window nothing moves
+    contribute(&system);
+    assert_eq!(end_time(&system), end);
+
+    // Inside the window the sale is pushed back, up to max_extension
+    advance_to_block(&system, end - 10);
+    contribute(&system);
+    assert_eq!(end_time(&system), end + 30);
+
+    advance_to_block(&system, end + 20);
+    contribute(&system);
+    assert_eq!(end_time(&system), end + 50);
+
+    advance_to_block(&system, end + 40);
+    contribute(&system);
+    assert_eq!(end_time(&system), end + 50);
+
+    // min_raise missed: finalize waits while the creator can still extend
+    advance_to_block(&system, end + 60);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Finalize should wait for the extension window");
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "ExtendForSoftCap", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Only the creator may extend");
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "ExtendForSoftCap", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ExtendForSoftCap should succeed");
+    assert_eq!(end_time(&system), end + 150);
+
+    // Contributions reopen, and the extension is single-use
+    contribute(&system);
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "ExtendForSoftCap", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Soft-cap extension is single-use");
+
+    advance_to_block(&system, end + 151);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Finalize should succeed");
+
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert_eq!(launch.status, LaunchStatus::RefundAvailable);
+    assert_eq!(launch.total_raised, 50 * ONE_VARA);
+}
+
+#[test]
+fn test_scheduled_activation_and_finalization() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    let input = create_test_launch_input(&system);
+    let (start, end) = (input.start_time, input.end_time);
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", input));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "CreateLaunch should succeed");
+    let launch_id: u64 = 0;
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "ScheduleLaunch", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Only the creator may schedule");
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "ScheduleLaunch", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ScheduleLaunch should succeed");
+
+    let gas: u64 = query(&system, &program, "GetAutomationGas", ());
+    let (schedule, reserved): (LaunchSchedule, u64) =
+        query::<_, Option<_>>(&system, &program, "GetSchedule", launch_id).unwrap();
+    assert_eq!((schedule.action, schedule.due_block, reserved), (ScheduledAction::Start, start, gas));
+
+    // Only the launchpad itself may run a step
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "RunAutomation", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "RunAutomation is internal");
+
+    // Activated at start_time, with finalization queued from the reservation
+    advance_to_block(&system, start - 1);
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert_eq!(launch.status, LaunchStatus::Pending);
+
+    advance_to_block(&system, start + 1);
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert_eq!(launch.status, LaunchStatus::Active);
+    let (schedule, reserved): (LaunchSchedule, u64) =
+        query::<_, Option<_>>(&system, &program, "GetSchedule", launch_id).unwrap();
+    assert_eq!((schedule.action, schedule.due_block, reserved), (ScheduledAction::Finalize, end + 1, 0));
+
+    let msg_id = program.send_bytes_with_value(
+        CONTRIBUTOR1,
+        encode_call("Launchpad", "Contribute", launch_id),
+        150 * ONE_VARA,
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Contribute should succeed");
+
+    // Finalized without anyone calling finalize
+    advance_to_block(&system, end + 2);
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert_eq!(launch.status, LaunchStatus::DistributionPending);
+    let schedule: Option<(LaunchSchedule, u64)> = query(&system, &program, "GetSchedule", launch_id);
+    assert!(schedule.is_none());
+}
+
+#[test]
+fn test_failed_scheduled_step_falls_back_to_manual_calls() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    // Too little gas to run a step
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "SetAutomationGas", 1_000_000_000u64));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "SetAutomationGas should succeed");
+
+    let input = create_test_launch_input(&system);
+    let start = input.start_time;
+    program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", input));
+    system.run_next_block();
+    let launch_id: u64 = 0;
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "ScheduleLaunch", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ScheduleLaunch should succeed");
+
+    // Nobody else can start it early
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "StartLaunch", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Only the creator may start before start_time");
+
+    // The activation runs out of gas and its error reply drops the schedule
+    advance_to_block(&system, start + 1);
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert_eq!(launch.status, LaunchStatus::Pending);
+    let schedule: Option<(LaunchSchedule, u64)> = query(&system, &program, "GetSchedule", launch_id);
+    assert!(schedule.is_none());
+
+    // Anyone can now start the overdue launch
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "StartLaunch", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "StartLaunch should succeed once overdue");
+
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert_eq!(launch.status, LaunchStatus::Active);
+}
+
+#[test]
+fn test_governance_approves_milestone() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let (launch_id, _) = setup_milestone_launch(&system, &program);
+
+    // Sale-time actions are no longer possible after the sale
+    let msg_id = program.send_bytes(
+        CONTRIBUTOR1,
+        encode_call("Launchpad", "CreateProposal", (launch_id, ProposalAction::CancelLaunch)),
+    );
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Cannot propose cancelling a finished sale");
+
+    program.send_bytes(
+        CONTRIBUTOR2,
+        encode_call("Launchpad", "CreateProposal", (launch_id, ProposalAction::ApproveMilestone(0))),
+    );
+    system.run_next_block();
+    vote(&system, &program, CONTRIBUTOR1, launch_id, 0, VoteChoice::For);
+    vote(&system, &program, CONTRIBUTOR2, launch_id, 0, VoteChoice::For);
+    advance_blocks(&system, 100);
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "ExecuteProposal", (launch_id, 0u32)));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ExecuteProposal should succeed");
+
+    let launch: Option<Launch> = query(&system, &program, "GetLaunch", launch_id);
+    let launch = launch.unwrap();
+    assert!(launch.milestones[0].completed);
+    assert!(!launch.milestones[1].completed);
+}
+
+// =============================================================================
+// ACCESS CONTROL TESTS
+// =============================================================================
+
+#[test]
+fn test_roles_gate_admin_functions() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let operator = ActorId::from(ANYONE);
+
+    // Deployer holds every role
+    for role in roles::ALL {
+        let held: bool = query(&system, &program, "HasRole", (role, ActorId::from(OWNER)));
+        assert!(held);
+    }
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Pause", ()));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Pause without PAUSER should fail");
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "GrantRole", (roles::PAUSER, operator)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Only admin can grant roles");
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "GrantRole", (roles::PAUSER, operator)));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "GrantRole should succeed");
+
+    let members: Vec<ActorId> = query(&system, &program, "GetRoleMembers", roles::PAUSER);
+    assert_eq!(members.len(), 2);
+    assert!(members.contains(&operator));
+
+    // A pauser can pause but holds no other powers
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Pause", ()));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Pause with PAUSER should succeed");
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "SetFeeRecipient", operator));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "SetFeeRecipient needs FEE_MANAGER");
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "RevokeRole", (roles::PAUSER, operator)));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "RevokeRole should succeed");
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Resume", ()));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Revoked pauser cannot resume");
+
+    let held: bool = query(&system, &program, "HasRole", (roles::PAUSER, operator));
+    assert!(!held);
+}
+
+#[test]
+fn test_renounce_role() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "RenounceRole", roles::FEE_MANAGER));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "RenounceRole should succeed");
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "SetFeeRecipient", ActorId::from(ANYONE)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Renounced role no longer authorizes");
+
+    // The last admin cannot walk away
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "RenounceRole", RoleId::DEFAULT_ADMIN));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Last admin cannot renounce");
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "RenounceRole", roles::PAUSER));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Cannot renounce a role not held");
+}
+
+#[test]
+fn test_two_step_ownership_transfer() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let new_owner = ActorId::from(CREATOR);
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "ProposeOwner", new_owner));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Only owner can propose");
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "ProposeOwner", new_owner));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ProposeOwner should succeed");
+
+    let pending: Option<ActorId> = query(&system, &program, "GetPendingOwner", ());
+    assert_eq!(pending, Some(new_owner));
+
+    // Cancel, then propose again
+    let msg_id = program.send_bytes(OWNER, encode_call_no_params("Launchpad", "CancelOwnershipTransfer"));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "CancelOwnershipTransfer should succeed");
+
+    let msg_id = program.send_bytes(CREATOR, encode_call_no_params("Launchpad", "AcceptOwnership"));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Cancelled transfer cannot be accepted");
+
+    program.send_bytes(OWNER, encode_call("Launchpad", "ProposeOwner", new_owner));
+    system.run_next_block();
+
+    let msg_id = program.send_bytes(ANYONE, encode_call_no_params("Launchpad", "AcceptOwnership"));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Only the proposed owner can accept");
+
+    let msg_id = program.send_bytes(CREATOR, encode_call_no_params("Launchpad", "AcceptOwnership"));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "AcceptOwnership should succeed");
+
+    let owner: ActorId = query(&system, &program, "GetOwner", ());
+    assert_eq!(owner, new_owner);
+    let pending: Option<ActorId> = query(&system, &program, "GetPendingOwner", ());
+    assert_eq!(pending, None);
+
+    // Roles move with ownership
+    let held: bool = query(&system, &program, "HasRole", (RoleId::DEFAULT_ADMIN, new_owner));
+    assert!(held);
+    let held: bool = query(&system, &program, "HasRole", (roles::PAUSER, ActorId::from(OWNER)));
+    assert!(!held);
+
+    let msg_id = program.send_bytes(OWNER, encode_call_no_params("Launchpad", "Pause"));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Previous owner lost its roles");
+
+    let msg_id = program.send_bytes(CREATOR, encode_call_no_params("Launchpad", "Pause"));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "New owner can pause");
+}
+
+#[test]
+fn test_renounce_ownership() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    program.send_bytes(OWNER, encode_call("Launchpad", "GrantRole", (roles::PAUSER, ActorId::from(ANYONE))));
+    system.run_next_block();
+
+    let launch_id = setup_active_launch(&system, &program, create_test_launch_input(&system));
+
+    let msg_id = program.send_bytes(OWNER, encode_call_no_params("Launchpad", "RenounceOwnership"));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "RenounceOwnership should succeed");
+
+    let owner: ActorId = query(&system, &program, "GetOwner", ());
+    assert_eq!(owner, ActorId::zero());
+
+    // Every role is gone, not just the owner's
+    let msg_id = program.send_bytes(ANYONE, encode_call_no_params("Launchpad", "Pause"));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "No pauser after renouncing");
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "CancelLaunch", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "No admin after renouncing");
+
+    // Permissionless paths keep working
+    advance_blocks(&system, 10000);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Finalize should still succeed");
+}
+
+// =============================================================================
+// MERKLE WHITELIST TESTS
+// =============================================================================
+
+#[test]
+fn test_merkle_whitelist() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    // Three-leaf tree: CONTRIBUTOR1 (no cap), CONTRIBUTOR2 (30 VARA cap), OWNER
+    let leaf1 = merkle::leaf(&ActorId::from(CONTRIBUTOR1), 0);
+    let leaf2 = merkle::leaf(&ActorId::from(CONTRIBUTOR2), 30 * ONE_VARA);
+    let leaf3 = merkle::leaf(&ActorId::from(OWNER), 0);
+    let root = merkle::hash_pair(&merkle::hash_pair(&leaf1, &leaf2), &leaf3);
+
+    let proof1 = MerkleProof { allocation: 0, proof: vec![leaf2, leaf3] };
+    let proof2 = MerkleProof { allocation: 30 * ONE_VARA, proof: vec![leaf1, leaf3] };
+
+    let mut input = create_test_launch_input(&system);
+    input.whitelist_root = Some(root);
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "AddToWhitelist", (launch_id, vec![ActorId::from(ANYONE)])));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Merkle launches have no on-chain list");
+
+    let msg_id = program.send_bytes_with_value(
+        CONTRIBUTOR1,
+        encode_call("Launchpad", "Contribute", launch_id),
+        50 * ONE_VARA,
+    );
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Contribution without proof should fail");
+
+    let msg_id = program.send_bytes_with_value(
+        CONTRIBUTOR1,
+        encode_call("Launchpad", "ContributeWithProof", (launch_id, proof1.clone())),
+        50 * ONE_VARA,
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Contribution with proof should succeed");
+
+    let msg_id = program.send_bytes_with_value(
+        NON_WHITELISTED,
+        encode_call("Launchpad", "ContributeWithProof", (launch_id, proof1.clone())),
+        50 * ONE_VARA,
+    );
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Someone else's proof should fail");
+
+    // The cap is part of the leaf, so it cannot be dropped
+    let uncapped = MerkleProof { allocation: 0, ..proof2.clone() };
+    let msg_id = program.send_bytes_with_value(
+        CONTRIBUTOR2,
+        encode_call("Launchpad", "ContributeWithProof", (launch_id, uncapped)),
+        50 * ONE_VARA,
+    );
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Tampered allocation should fail");
+
+    let msg_id = program.send_bytes_with_value(
+        CONTRIBUTOR2,
+        encode_call("Launchpad", "ContributeWithProof", (launch_id, proof2)),
+        50 * ONE_VARA,
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Capped contribution should succeed");
+
+    let contribution: u128 = query(&system, &program, "GetContribution", (launch_id, ActorId::from(CONTRIBUTOR2)));
+    assert_eq!(contribution, 30 * ONE_VARA, "Leaf allocation caps the contribution");
+
+    let valid: bool = query(&system, &program, "VerifyWhitelistProof", (launch_id, ActorId::from(CONTRIBUTOR1), proof1.clone()));
+    assert!(valid);
+    let valid: bool = query(&system, &program, "VerifyWhitelistProof", (launch_id, ActorId::from(NON_WHITELISTED), proof1));
+    assert!(!valid);
+}
+
+// =============================================================================
+// SALE PHASE TESTS
+// =============================================================================
+
+#[test]
+fn test_tiered_sale_phases() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    let mut input = create_test_launch_input(&system);
+    let start = input.start_time;
+    let end = input.end_time;
+    input.phases = vec![
+        SalePhase {
+            name: "Seed".into(),
+            start_time: start,
+            end_time: start + 30,
+            price_per_token: ONE_VARA / 2000,
+            max_per_wallet: 20 * ONE_VARA,
+            eligibility: PhaseEligibility::Addresses([ActorId::from(CONTRIBUTOR1)].into()),
+        },
+        SalePhase {
+            name: "Public".into(),
+            start_time: start + 40,
+            end_time: end,
+            price_per_token: ONE_VARA / 1000,
+            max_per_wallet: 100 * ONE_VARA,
+            eligibility: PhaseEligibility::Public,
+        },
+    ];
+
+    // Overlapping phases are rejected
+    let mut overlapping = input.clone();
+    overlapping.phases[1].start_time = start + 30;
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", overlapping));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Overlapping phases should fail");
+
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    let active: Option<(u32, SalePhase)> = query(&system, &program, "GetActivePhase", launch_id);
+    assert_eq!(active.map(|(index, _)| index), Some(0));
+
+    let msg_id = program.send_bytes_with_value(
+        CONTRIBUTOR2,
+        encode_call("Launchpad", "Contribute", launch_id),
+        10 * ONE_VARA,
+    );
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Ineligible address should fail");
+
+    // The seed cap limits the contribution; the rest is refunded
+    let msg_id = program.send_bytes_with_value(
+        CONTRIBUTOR1,
+        encode_call("Launchpad", "Contribute", launch_id),
+        50 * ONE_VARA,
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Seed contribution should succeed");
+
+    let seed: u128 = query(&system, &program, "GetPhaseContribution", (launch_id, 0u32, ActorId::from(CONTRIBUTOR1)));
+    assert_eq!(seed, 20 * ONE_VARA);
+    let tokens: u128 = query(&system, &program, "GetTokensPurchased", (launch_id, ActorId::from(CONTRIBUTOR1)));
+    assert_eq!(tokens, 40_000, "Seed price applies");
+
+    // Between phases nobody can contribute
+    advance_blocks(&system, 20);
+    let active: Option<(u32, SalePhase)> = query(&system, &program, "GetActivePhase", launch_id);
+    assert!(active.is_none());
+
+    let msg_id = program.send_bytes_with_value(
+        CONTRIBUTOR1,
+        encode_call("Launchpad", "Contribute", launch_id),
+        10 * ONE_VARA,
+    );
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Contribution between phases should fail");
+
+    advance_blocks(&system, 10);
+    let active: Option<(u32, SalePhase)> = query(&system, &program, "GetActivePhase", launch_id);
+    assert_eq!(active.map(|(index, phase)| (index, phase.name)), Some((1, "Public".into())));
+
+    let msg_id = program.send_bytes_with_value(
+        CONTRIBUTOR2,
+        encode_call("Launchpad", "Contribute", launch_id),
+        10 * ONE_VARA,
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Public contribution should succeed");
+
+    let tokens: u128 = query(&system, &program, "GetTokensPurchased", (launch_id, ActorId::from(CONTRIBUTOR2)));
+    assert_eq!(tokens, 10_000, "Public price applies");
+}
+
+// =============================================================================
+// DUTCH AUCTION TESTS
+// =============================================================================
+
+#[test]
+fn test_dutch_auction_settles_at_clearing_price() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let stablecoin = deploy_stablecoin(&system, &[CONTRIBUTOR1, CONTRIBUTOR2], 1000 * ONE_VARA);
+
+    let start_price = 2 * ONE_VARA / 1000;
+    let floor_price = ONE_VARA / 1000;
+    let mut input = create_test_launch_input(&system);
+    input.total_tokens = 100_000;
+    input.min_raise = 50 * ONE_VARA;
+    input.max_raise = 200 * ONE_VARA;
+    input.end_time = input.start_time + 100;
+    input.payment_token = TokenType::Vft(stablecoin);
+    input.auction = Some(DutchAuction { start_price, floor_price, curve: DecayCurve::Linear });
+
+    let mut bad_floor = input.clone();
+    bad_floor.auction = Some(DutchAuction { start_price, floor_price: start_price + 1, curve: DecayCurve::Linear });
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", bad_floor));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Floor above start price should fail");
+
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    let early_price: Option<u128> = query(&system, &program, "GetCurrentPrice", launch_id);
+    let early_price = early_price.unwrap();
+    assert!(early_price < start_price && early_price > floor_price, "Price decays from the start");
+
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR1, launch_id, 60 * ONE_VARA);
+    let paid1: u128 = query(&system, &program, "GetContribution", (launch_id, ActorId::from(CONTRIBUTOR1)));
+    let tokens1: u128 = query(&system, &program, "GetTokensPurchased", (launch_id, ActorId::from(CONTRIBUTOR1)));
+
+    // Later buyers pay less and sell out the remaining tokens
+    advance_blocks(&system, 40);
+    let late_price: Option<u128> = query(&system, &program, "GetCurrentPrice", launch_id);
+    assert!(late_price.unwrap() < early_price);
+
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR2, launch_id, 200 * ONE_VARA);
+    let paid2: u128 = query(&system, &program, "GetContribution", (launch_id, ActorId::from(CONTRIBUTOR2)));
+    let tokens2: u128 = query(&system, &program, "GetTokensPurchased", (launch_id, ActorId::from(CONTRIBUTOR2)));
+    assert_eq!(tokens1 + tokens2, 100_000, "Auction should sell out");
+    let clearing_price = paid2 / tokens2;
+    assert!(paid1 / tokens1 > clearing_price);
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Sold-out auction should finalize early");
+
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert_eq!(launch.clearing_price, Some(clearing_price));
+    assert_eq!(launch.price_per_token, clearing_price);
+    assert_eq!(launch.total_raised, 100_000 * clearing_price);
+
+    let excess1: u128 = query(&system, &program, "GetExcessRefund", (launch_id, ActorId::from(CONTRIBUTOR1)));
+    assert_eq!(excess1, paid1 - tokens1 * clearing_price);
+    let excess2: u128 = query(&system, &program, "GetExcessRefund", (launch_id, ActorId::from(CONTRIBUTOR2)));
+    assert_eq!(excess2, 0, "The last buyer paid the clearing price");
+
+    // Claiming delivers the tokens and refunds the overpayment
+    let msg_id = program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "ClaimTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ClaimTokens should succeed");
+
+    assert_eq!(token_balance(&system, launch.token_address, CONTRIBUTOR1), tokens1);
+    assert_eq!(
+        token_balance(&system, stablecoin, CONTRIBUTOR1),
+        1000 * ONE_VARA - tokens1 * clearing_price
+    );
+    let excess1: u128 = query(&system, &program, "GetExcessRefund", (launch_id, ActorId::from(CONTRIBUTOR1)));
+    assert_eq!(excess1, 0);
+}
+
+// =============================================================================
+// OVERSUBSCRIPTION TESTS
+// =============================================================================
+
+#[test]
+fn test_pro_rata_oversubscription() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let stablecoin = deploy_stablecoin(&system, &[CONTRIBUTOR1, CONTRIBUTOR2], 1000 * ONE_VARA);
+
+    let mut input = create_test_launch_input(&system);
+    input.max_raise = 100 * ONE_VARA;
+    input.min_raise = 50 * ONE_VARA;
+    input.payment_token = TokenType::Vft(stablecoin);
+    input.pro_rata = true;
+    let price = input.price_per_token;
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    // Demand beyond the hard cap is accepted during the window
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR1, launch_id, 150 * ONE_VARA);
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR2, launch_id, 50 * ONE_VARA);
+    assert_eq!(token_balance(&system, stablecoin, LAUNCHPAD), 200 * ONE_VARA);
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Pro-rata sales run until end time");
+
+    advance_blocks(&system, 10000);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Finalize should succeed");
+
+    // Twice oversubscribed: everyone gets half and is refunded the rest
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert_eq!(launch.total_raised, 100 * ONE_VARA);
+
+    let tokens1: u128 = query(&system, &program, "GetTokensPurchased", (launch_id, ActorId::from(CONTRIBUTOR1)));
+    assert_eq!(tokens1, 75 * ONE_VARA / price);
+    let excess1: u128 = query(&system, &program, "GetExcessRefund", (launch_id, ActorId::from(CONTRIBUTOR1)));
+    assert_eq!(excess1, 75 * ONE_VARA);
+    let excess2: u128 = query(&system, &program, "GetExcessRefund", (launch_id, ActorId::from(CONTRIBUTOR2)));
+    assert_eq!(excess2, 25 * ONE_VARA);
+
+    let msg_id = program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "ClaimTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ClaimTokens should succeed");
+    assert_eq!(token_balance(&system, launch.token_address, CONTRIBUTOR1), tokens1);
+    assert_eq!(token_balance(&system, stablecoin, CONTRIBUTOR1), 925 * ONE_VARA);
+
+    // The creator only receives the accepted raise
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "WithdrawFunds", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "WithdrawFunds should succeed");
+    assert_eq!(token_balance(&system, stablecoin, CREATOR), 98 * ONE_VARA);
+
+    let msg_id = program.send_bytes(CONTRIBUTOR2, encode_call("Launchpad", "ClaimTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ClaimTokens should succeed");
+    assert_eq!(token_balance(&system, stablecoin, CONTRIBUTOR2), 975 * ONE_VARA);
+    assert_eq!(token_balance(&system, stablecoin, LAUNCHPAD), 2 * ONE_VARA, "Only the platform fee remains");
+}
+
+#[test]
+fn test_pro_rata_oversubscription_large_amounts() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let supply = 10u128.pow(31);
+    let stablecoin = deploy_stablecoin(&system, &[CONTRIBUTOR1, CONTRIBUTOR2], supply);
+
+    // Cap and demand large enough that `max_raise * requested` overflows u128
+    let cap = 10u128.pow(30);
+    let mut input = create_test_launch_input(&system);
+    input.total_tokens = cap;
+    input.price_per_token = 1;
+    input.max_raise = cap;
+    input.min_raise = cap / 10;
+    input.max_per_wallet = supply;
+    input.payment_token = TokenType::Vft(stablecoin);
+    input.pro_rata = true;
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR1, launch_id, cap / 2 * 3);
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR2, launch_id, cap / 2);
+
+    advance_blocks(&system, 10000);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Finalize should succeed");
+
+    // Twice oversubscribed: allocations are still exactly halved
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert_eq!(launch.total_raised, cap);
+    assert_eq!(launch.tokens_remaining, 0);
+
+    let tokens1: u128 = query(&system, &program, "GetTokensPurchased", (launch_id, ActorId::from(CONTRIBUTOR1)));
+    assert_eq!(tokens1, cap / 4 * 3);
+    let tokens2: u128 = query(&system, &program, "GetTokensPurchased", (launch_id, ActorId::from(CONTRIBUTOR2)));
+    assert_eq!(tokens2, cap / 4);
+    let excess1: u128 = query(&system, &program, "GetExcessRefund", (launch_id, ActorId::from(CONTRIBUTOR1)));
+    assert_eq!(excess1, cap / 4 * 3);
+    let excess2: u128 = query(&system, &program, "GetExcessRefund", (launch_id, ActorId::from(CONTRIBUTOR2)));
+    assert_eq!(excess2, cap / 4);
+
+    let msg_id = program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "ClaimTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ClaimTokens should succeed");
+    assert_eq!(token_balance(&system, launch.token_address, CONTRIBUTOR1), tokens1);
+    assert_eq!(token_balance(&system, stablecoin, CONTRIBUTOR1), supply - cap / 4 * 3);
+}
+
+// =============================================================================
+// BONDING CURVE TESTS
+// =============================================================================
+
+#[test]
+fn test_bonding_curve_sell_back_and_graduation() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let stablecoin = deploy_stablecoin(&system, &[CONTRIBUTOR1, CONTRIBUTOR2], 1000 * ONE_VARA);
+
+    // Ten steps of 100 tokens priced 0.1, 0.2, ... 1.0
+    let mut input = create_test_launch_input(&system);
+    input.total_tokens = 1000;
+    input.min_raise = 100 * ONE_VARA;
+    input.max_raise = 550 * ONE_VARA;
+    input.max_per_wallet = 400 * ONE_VARA;
+    input.payment_token = TokenType::Vft(stablecoin);
+    input.bonding_curve = Some(BondingCurve {
+        kind: CurveKind::Linear { increment: ONE_VARA / 10 },
+        initial_price: ONE_VARA / 10,
+        step_size: 100,
+        target_reserve: 300 * ONE_VARA,
+    });
+
+    let mut unreachable = input.clone();
+    unreachable.max_raise = 600 * ONE_VARA;
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", unreachable));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Max raise above the curve's value should fail");
+
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR1, launch_id, 30 * ONE_VARA);
+    let tokens1: u128 = query(&system, &program, "GetTokensPurchased", (launch_id, ActorId::from(CONTRIBUTOR1)));
+    assert_eq!(tokens1, 200, "First two steps cost 10 + 20");
+    let price: Option<u128> = query(&system, &program, "GetCurrentPrice", launch_id);
+    assert_eq!(price, Some(3 * ONE_VARA / 10));
+
+    // Selling back returns the curve price of the last tokens sold
+    let msg_id = program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "SellTokens", (launch_id, 50u128)));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "SellTokens should succeed");
+    assert_eq!(token_balance(&system, stablecoin, CONTRIBUTOR1), 980 * ONE_VARA);
+    let tokens1: u128 = query(&system, &program, "GetTokensPurchased", (launch_id, ActorId::from(CONTRIBUTOR1)));
+    assert_eq!(tokens1, 150);
+
+    let msg_id = program.send_bytes(CONTRIBUTOR2, encode_call("Launchpad", "SellTokens", (launch_id, 1u128)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Cannot sell tokens not held");
+
+    // Crossing the target reserve graduates the launch
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR2, launch_id, 300 * ONE_VARA);
+    let tokens2: u128 = query(&system, &program, "GetTokensPurchased", (launch_id, ActorId::from(CONTRIBUTOR2)));
+    assert_eq!(tokens2, 600);
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert_eq!(launch.total_raised, 320 * ONE_VARA);
+
+    let msg_id = program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "SellTokens", (launch_id, 50u128)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "No sell-back after graduation");
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Graduated launch should finalize early");
+
+    let msg_id = program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "ClaimTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ClaimTokens should succeed");
+    assert_eq!(token_balance(&system, launch.token_address, CONTRIBUTOR1), 150);
+}
+
+// =============================================================================
+// DEX LIQUIDITY TESTS
+// =============================================================================
+
+#[test]
+fn test_liquidity_seeded_into_dex() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let stablecoin = deploy_stablecoin(&system, &[CONTRIBUTOR1, CONTRIBUTOR2], 1000 * ONE_VARA);
+    let amm = deploy_amm(&system);
+
+    let mut input = create_test_launch_input(&system);
+    input.payment_token = TokenType::Vft(stablecoin);
+    input.liquidity = Some(LiquidityConfig { raise_bps: 2000, lock_duration: 100 });
+    let price = input.price_per_token;
+
+    let mut invalid = input.clone();
+    invalid.liquidity = Some(LiquidityConfig { raise_bps: 10_001, lock_duration: 100 });
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", invalid));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Liquidity share above 100% should fail");
+
+    let mut unlocked = input.clone();
+    unlocked.liquidity = Some(LiquidityConfig { raise_bps: 2000, lock_duration: 0 });
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", unlocked));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Liquidity must be locked");
+
+    // The platform minimum applies to every new launch
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "SetMinLiquidityLock", 1000u32));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Only CONFIG_ADMIN can set the minimum lock");
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "SetMinLiquidityLock", 1000u32));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "SetMinLiquidityLock should succeed");
+    assert_eq!(query::<_, u32>(&system, &program, "GetMinLiquidityLock", ()), 1000);
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", input.clone()));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Lock below the platform minimum should fail");
+    program.send_bytes(OWNER, encode_call("Launchpad", "SetMinLiquidityLock", 100u32));
+    system.run_next_block();
+
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    // The reserve covers the share of a full raise at the sale price
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert_eq!(launch.liquidity_reserve, 200 * ONE_VARA / price);
+    assert_eq!(token_balance(&system, launch.token_address, LAUNCHPAD), launch.total_tokens + launch.liquidity_reserve);
+
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR1, launch_id, 60 * ONE_VARA);
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR2, launch_id, 40 * ONE_VARA);
+
+    // Without a DEX the launch still succeeds and seeding waits for a retry
+    advance_blocks(&system, 10000);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Finalize should succeed without a DEX");
+
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert!(launch.is_successful());
+    assert!(launch.seeded_liquidity.is_none());
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "SetDex", Some(amm)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Only a config admin can set the DEX");
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "SetDex", Some(amm)));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "SetDex should succeed");
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "SeedLiquidity", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "SeedLiquidity should succeed");
+
+    // 20% of the raise is paired with tokens at the sale price
+    let tokens = 20 * ONE_VARA / price;
+    assert_eq!(token_balance(&system, stablecoin, AMM), 20 * ONE_VARA);
+    assert_eq!(token_balance(&system, launch.token_address, AMM), tokens);
+
+    let liquidity = amm_liquidity(&system, launch.token_address, Some(stablecoin), LAUNCHPAD);
+    assert!(liquidity > 0, "Launchpad should hold the pool shares");
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    let seeded = launch.seeded_liquidity.expect("Liquidity should be recorded");
+    assert_eq!((seeded.funds, seeded.tokens, seeded.liquidity), (20 * ONE_VARA, tokens, liquidity));
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "SeedLiquidity", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Liquidity can only be seeded once");
+
+    // The creator receives the rest of the raise minus the platform fee
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "WithdrawFunds", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "WithdrawFunds should succeed");
+    assert_eq!(token_balance(&system, stablecoin, CREATOR), 80 * ONE_VARA * 98 / 100);
+}
+
+#[test]
+fn test_locked_liquidity_withdrawn_after_expiry() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let stablecoin = deploy_stablecoin(&system, &[CONTRIBUTOR1], 1000 * ONE_VARA);
+    let amm = deploy_amm(&system);
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "SetDex", Some(amm)));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "SetDex should succeed");
+
+    let mut input = create_test_launch_input(&system);
+    input.payment_token = TokenType::Vft(stablecoin);
+    input.liquidity = Some(LiquidityConfig { raise_bps: 5000, lock_duration: 100 });
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR1, launch_id, 100 * ONE_VARA);
+    advance_blocks(&system, 10000);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Finalize should succeed");
+
+    // Finalize seeded the pool; the shares are locked in the launchpad
+    let (lock, unlocked): (LiquidityLock, bool) =
+        query::<_, Option<(LiquidityLock, bool)>>(&system, &program, "GetLiquidityLock", launch_id).unwrap();
+    assert!(!unlocked);
+    assert!(!lock.withdrawn);
+    assert_eq!(lock.unlock_at, lock.locked_at + 100);
+    let token_address = lock.token;
+    assert_eq!(amm_liquidity(&system, token_address, Some(stablecoin), LAUNCHPAD), lock.liquidity);
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "WithdrawLockedLiquidity", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Withdrawal before expiry should fail");
+
+    advance_blocks(&system, 100);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "WithdrawLockedLiquidity", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Only the creator can withdraw liquidity");
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "WithdrawLockedLiquidity", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "WithdrawLockedLiquidity should succeed");
+
+    assert_eq!(amm_liquidity(&system, token_address, Some(stablecoin), CREATOR), lock.liquidity);
+    assert_eq!(amm_liquidity(&system, token_address, Some(stablecoin), LAUNCHPAD), 0);
+    let (lock, unlocked): (LiquidityLock, bool) =
+        query::<_, Option<(LiquidityLock, bool)>>(&system, &program, "GetLiquidityLock", launch_id).unwrap();
+    assert!(unlocked && lock.withdrawn);
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "WithdrawLockedLiquidity", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Liquidity can only be withdrawn once");
+}
+
+#[test]
+fn test_unseeded_liquidity_released_after_seeding_period() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let stablecoin = deploy_stablecoin(&system, &[CONTRIBUTOR1], 1000 * ONE_VARA);
+
+    let mut input = create_test_launch_input(&system);
+    input.payment_token = TokenType::Vft(stablecoin);
+    input.liquidity = Some(LiquidityConfig { raise_bps: 5000, lock_duration: 100 });
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    // No DEX is ever set, so seeding fails at finalization
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR1, launch_id, 100 * ONE_VARA);
+    advance_blocks(&system, 10000);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Finalize should succeed");
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "WithdrawFunds", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "WithdrawFunds should succeed");
+    assert_eq!(token_balance(&system, stablecoin, CREATOR), 49 * ONE_VARA);
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "ReleaseUnseededLiquidity", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Release before the seeding period ends should fail");
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "SetLiquiditySeedingPeriod", 100u32));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Only CONFIG_ADMIN can set the seeding period");
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "SetLiquiditySeedingPeriod", 100u32));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "SetLiquiditySeedingPeriod should succeed");
+    assert_eq!(query::<_, u32>(&system, &program, "GetLiquiditySeedingPeriod", ()), 100);
+
+    advance_blocks(&system, 100);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "ReleaseUnseededLiquidity", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ReleaseUnseededLiquidity should succeed");
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "SeedLiquidity", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Released liquidity can no longer be seeded");
+
+    // The liquidity share is now the creator's, and so is the whole reserve
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "WithdrawFunds", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "WithdrawFunds should succeed");
+    assert_eq!(token_balance(&system, stablecoin, CREATOR), 98 * ONE_VARA);
+
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert!(launch.liquidity.is_none());
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "ReturnUnsoldTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ReturnUnsoldTokens should succeed");
+    assert_eq!(
+        token_balance(&system, launch.token_address, CREATOR),
+        launch.tokens_remaining + launch.liquidity_reserve,
+    );
+}
+
+// =============================================================================
+// TEAM VESTING TESTS
+// =============================================================================
+
+#[test]
+fn test_team_allocation_vests_per_beneficiary() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    // CONTRIBUTOR2 doubles as a team member here
+    let mut input = create_test_launch_input(&system);
+    input.min_raise = 50 * ONE_VARA;
+    input.team_allocation = Some(TeamAllocation {
+        total: 10_000,
+        vesting: VestingConfig::new(input.end_time, 100, 1000),
+        beneficiaries: vec![
+            TeamShare { beneficiary: ActorId::from(CREATOR), share_bps: 6000 },
+            TeamShare { beneficiary: ActorId::from(CONTRIBUTOR2), share_bps: 4000 },
+        ],
+    });
+
+    let mut invalid = input.clone();
+    invalid.team_allocation.as_mut().unwrap().beneficiaries[1].share_bps = 3000;
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", invalid));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Team shares must sum to 100%");
+
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    // The team allocation is minted on top of the sale
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert_eq!(token_balance(&system, launch.token_address, LAUNCHPAD), launch.total_tokens + 10_000);
+    assert_eq!(launch.team_tokens.get(&ActorId::from(CREATOR)), Some(&6000));
+
+    // Token info reports the whole minted supply (LaunchTokenInfo decoded as a tuple)
+    let info: Option<(ActorId, u128, u128, u128, bool)> = query(&system, &program, "GetLaunchTokenInfo", launch_id);
+    let (token_address, total_supply, circulating_supply, _, _) = info.unwrap();
+    assert_eq!(token_address, launch.token_address);
+    assert_eq!(total_supply, launch.total_tokens + 10_000);
+    assert_eq!(circulating_supply, 0);
+
+    let msg_id = program.send_bytes_with_value(
+        CONTRIBUTOR1,
+        encode_call("Launchpad", "Contribute", launch_id),
+        100 * ONE_VARA,
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Contribute should succeed");
+
+    advance_blocks(&system, 10000);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Finalize should succeed");
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "ClaimTeamTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Nothing vests before the cliff");
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "ClaimTeamTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Only beneficiaries can claim team tokens");
+
+    // Part-way through the schedule only the linear share is released
+    advance_blocks(&system, 500);
+    let claimable: u128 = query(&system, &program, "GetClaimableTeamTokens", (launch_id, ActorId::from(CREATOR)));
+    assert!(claimable > 0 && claimable < 6000);
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "ClaimTeamTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ClaimTeamTokens should succeed");
+    let partial = token_balance(&system, launch.token_address, CREATOR);
+    assert!(partial >= claimable && partial < 6000);
+
+    // Team claims follow a registered claim address
+    let msg_id = program.send_bytes(
+        CONTRIBUTOR2,
+        encode_call("Launchpad", "SetClaimAddress", Some(ActorId::from(NON_WHITELISTED))),
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "SetClaimAddress should succeed");
+
+    // After the schedule ends each beneficiary has their full share
+    advance_blocks(&system, 1000);
+    for (beneficiary, payee, share) in [(CREATOR, CREATOR, 6000), (CONTRIBUTOR2, NON_WHITELISTED, 4000)] {
+        let msg_id = program.send_bytes(beneficiary, encode_call("Launchpad", "ClaimTeamTokens", launch_id));
+        let result = system.run_next_block();
+        assert!(result.succeed.contains(&msg_id), "ClaimTeamTokens should succeed");
+        assert_eq!(token_balance(&system, launch.token_address, payee), share);
+    }
+    assert_eq!(token_balance(&system, launch.token_address, CONTRIBUTOR2), 0);
+
+    let claimable: u128 = query(&system, &program, "GetClaimableTeamTokens", (launch_id, ActorId::from(CREATOR)));
+    assert_eq!(claimable, 0);
+}
+
+#[test]
+fn test_vesting_tge_stepped_and_custom_schedules() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    // Buyers get 100_000 tokens for 100 VARA; vesting starts 100 blocks after the sale
+    // Both launches open late enough to be created one after the other
+    let mut stepped = create_test_launch_input(&system);
+    stepped.start_time += 30;
+    let start = stepped.end_time + 100;
+    stepped.vesting_config = Some(VestingConfig {
+        tge_unlock_bps: 2000,
+        curve: VestingCurve::Stepped { interval: 250 },
+        ..VestingConfig::new(start, 0, 1000)
+    });
+
+    let mut custom = stepped.clone();
+    custom.vesting_config = Some(VestingConfig {
+        curve: VestingCurve::Custom(vec![(start + 100, 5000), (start + 500, 9000)]),
+        ..VestingConfig::new(start, 0, 0)
+    });
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", custom.clone()));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Custom schedule must reach 100%");
+    custom.vesting_config.as_mut().unwrap().curve = VestingCurve::Custom(vec![(start + 100, 5000), (start + 500, 10_000)]);
+
+    let stepped_id = setup_active_launch(&system, &program, stepped);
+    let custom_id = setup_active_launch(&system, &program, custom);
+    advance_blocks(&system, 15);
+    let claimable = |launch_id: u64, user: u64| -> u128 {
+        query(&system, &program, "GetClaimableTokens", (launch_id, ActorId::from(user)))
+    };
+    let claim = |launch_id: u64, user: u64| {
+        let msg_id = program.send_bytes(user, encode_call("Launchpad", "ClaimTokens", launch_id));
+        let result = system.run_next_block();
+        assert!(result.succeed.contains(&msg_id), "ClaimTokens should succeed");
+    };
+
+    for (launch_id, contributor) in [(stepped_id, CONTRIBUTOR1), (custom_id, CONTRIBUTOR2)] {
+        let msg_id = program.send_bytes_with_value(
+            contributor,
+            encode_call("Launchpad", "Contribute", launch_id),
+            100 * ONE_VARA,
+        );
+        let result = system.run_next_block();
+        assert!(result.succeed.contains(&msg_id), "Contribute should succeed");
+    }
+
+    advance_to_block(&system, start - 50);
+    for launch_id in [stepped_id, custom_id] {
+        let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+        let result = system.run_next_block();
+        assert!(result.succeed.contains(&msg_id), "Finalize should succeed");
+    }
+    assert_eq!(claimable(stepped_id, CONTRIBUTOR1), 0, "Nothing unlocks before TGE");
+
+    let token = query::<_, Option<Launch>>(&system, &program, "GetLaunch", stepped_id).unwrap().token_address;
+
+    // TGE releases 20%, then 80% in four steps of 250 blocks
+    advance_to_block(&system, start + 10);
+    assert_eq!(claimable(stepped_id, CONTRIBUTOR1), 20_000);
+    claim(stepped_id, CONTRIBUTOR1);
+    assert_eq!(token_balance(&system, token, CONTRIBUTOR1), 20_000);
+
+    advance_to_block(&system, start + 240);
+    assert_eq!(claimable(stepped_id, CONTRIBUTOR1), 0, "No release between steps");
+    advance_to_block(&system, start + 260);
+    assert_eq!(claimable(stepped_id, CONTRIBUTOR1), 20_000);
+
+    // The custom schedule unlocks only at its points
+    assert_eq!(claimable(custom_id, CONTRIBUTOR2), 50_000);
+    advance_to_block(&system, start + 510);
+    assert_eq!(claimable(custom_id, CONTRIBUTOR2), 100_000);
+
+    advance_to_block(&system, start + 1000);
+    claim(stepped_id, CONTRIBUTOR1);
+    assert_eq!(token_balance(&system, token, CONTRIBUTOR1), 100_000);
+}
+
+#[test]
+fn test_linear_vesting_with_18_decimal_amounts() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let one_token = 10u128.pow(18);
+    let supply = 1_000_000 * one_token;
+    let stablecoin = deploy_stablecoin(&system, &[CONTRIBUTOR1], supply);
+
+    // 1000 whole tokens at 18 decimals, released linearly over 1000 blocks
+    let mut input = create_test_launch_input(&system);
+    let start = input.end_time + 100;
+    input.total_tokens = supply;
+    input.price_per_token = 1;
+    input.min_raise = 100 * one_token;
+    input.max_raise = 10_000 * one_token;
+    input.max_per_wallet = 10_000 * one_token;
+    input.payment_token = TokenType::Vft(stablecoin);
+    input.vesting_config = Some(VestingConfig {
+        tge_unlock_bps: 1000,
+        ..VestingConfig::new(start, 0, 1000)
+    });
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR1, launch_id, 1000 * one_token);
+
+    advance_to_block(&system, start - 50);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Finalize should succeed");
+
+    // About halfway: the 10% TGE share plus the elapsed part of the other 90%
+    advance_to_block(&system, start + 500);
+    let vested_at = |block: u32| 100 * one_token + 900 * one_token * (block - start) as u128 / 1000;
+    let claimable: u128 = query(&system, &program, "GetClaimableTokens", (launch_id, ActorId::from(CONTRIBUTOR1)));
+    assert_eq!(claimable, vested_at(system.block_height()));
+    assert!(claimable > 550 * one_token && claimable < 552 * one_token);
+
+    let msg_id = program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "ClaimTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ClaimTokens should succeed");
+    let claimed = vested_at(system.block_height());
+
+    let token = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap().token_address;
+    assert_eq!(token_balance(&system, token, CONTRIBUTOR1), claimed);
+}
+
+#[test]
+fn test_vesting_position_transfer() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    let mut input = create_test_launch_input(&system);
+    let start = input.end_time + 100;
+    input.vesting_config = Some(VestingConfig::new(start, 0, 1000));
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    let msg_id = program.send_bytes_with_value(
+        CONTRIBUTOR1,
+        encode_call("Launchpad", "Contribute", launch_id),
+        100 * ONE_VARA,
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Contribute should succeed");
+
+    advance_to_block(&system, start);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Finalize should succeed");
+
+    advance_to_block(&system, start + 500);
+    let msg_id = program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "ClaimTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ClaimTokens should succeed");
+    let claimed: u128 = query(&system, &program, "GetClaimed", (launch_id, ActorId::from(CONTRIBUTOR1)));
+    assert!(claimed > 0 && claimed < 100_000);
+
+    let transfer = |amount: u128| {
+        let msg_id = program.send_bytes(
+            CONTRIBUTOR1,
+            encode_call("Launchpad", "TransferPosition", (launch_id, ActorId::from(CONTRIBUTOR2), amount)),
+        );
+        system.run_next_block().succeed.contains(&msg_id)
+    };
+    assert!(!transfer(40_000), "Transfers are disabled by default");
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "SetPositionTransfers", (launch_id, true)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Only the creator can enable transfers");
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "SetPositionTransfers", (launch_id, true)));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "SetPositionTransfers should succeed");
+
+    let unclaimed = 100_000 - claimed;
+    assert!(!transfer(unclaimed + 1), "Cannot transfer claimed tokens");
+    assert!(transfer(unclaimed / 2), "TransferPosition should succeed");
+
+    // Purchases and claims move together, keeping both on the schedule
+    let purchased1: u128 = query(&system, &program, "GetTokensPurchased", (launch_id, ActorId::from(CONTRIBUTOR1)));
+    let purchased2: u128 = query(&system, &program, "GetTokensPurchased", (launch_id, ActorId::from(CONTRIBUTOR2)));
+    let claimed1: u128 = query(&system, &program, "GetClaimed", (launch_id, ActorId::from(CONTRIBUTOR1)));
+    let claimed2: u128 = query(&system, &program, "GetClaimed", (launch_id, ActorId::from(CONTRIBUTOR2)));
+    assert_eq!(purchased1 + purchased2, 100_000);
+    assert_eq!(claimed1 + claimed2, claimed);
+    assert_eq!(purchased2 - claimed2, unclaimed / 2);
+    assert_eq!(claimed2 * 100_000 / purchased2, claimed, "Recipient inherits the vesting progress");
+
+    let token = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap().token_address;
+    advance_to_block(&system, start + 1000);
+    for user in [CONTRIBUTOR1, CONTRIBUTOR2] {
+        let msg_id = program.send_bytes(user, encode_call("Launchpad", "ClaimTokens", launch_id));
+        let result = system.run_next_block();
+        assert!(result.succeed.contains(&msg_id), "ClaimTokens should succeed");
+    }
+    assert_eq!(token_balance(&system, token, CONTRIBUTOR1), 100_000 - unclaimed / 2);
+    assert_eq!(token_balance(&system, token, CONTRIBUTOR2), unclaimed / 2);
+}
+
+#[test]
+fn test_revoke_team_vesting() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+
+    // Two team members with 5000 tokens each, vesting linearly over 1000 blocks
+    let mut input = create_test_launch_input(&system);
+    let start = input.end_time + 100;
+    input.team_allocation = Some(TeamAllocation {
+        total: 10_000,
+        vesting: VestingConfig::new(start, 0, 1000),
+        beneficiaries: vec![
+            TeamShare { beneficiary: ActorId::from(CONTRIBUTOR1), share_bps: 5000 },
+            TeamShare { beneficiary: ActorId::from(CONTRIBUTOR2), share_bps: 5000 },
+        ],
+    });
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    let msg_id = program.send_bytes_with_value(
+        ANYONE,
+        encode_call("Launchpad", "Contribute", launch_id),
+        100 * ONE_VARA,
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Contribute should succeed");
+
+    advance_to_block(&system, start);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Finalize should succeed");
+
+    let token = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap().token_address;
+    let revoke = |caller: u64, beneficiary: u64, at_block: u32, burn: bool| {
+        let msg_id = program.send_bytes(
+            caller,
+            encode_call("Launchpad", "RevokeTeamVesting", (launch_id, ActorId::from(beneficiary), at_block, burn)),
+        );
+        system.run_next_block().succeed.contains(&msg_id)
+    };
+
+    advance_to_block(&system, start + 400);
+    assert!(!revoke(ANYONE, CONTRIBUTOR1, start + 500, false), "Only the creator or an admin can revoke");
+    assert!(!revoke(CREATOR, CONTRIBUTOR1, start + 300, false), "Vested tokens cannot be revoked");
+
+    // The creator takes back what would vest after block start + 500
+    assert!(revoke(CREATOR, CONTRIBUTOR1, start + 500, false), "Creator revocation should succeed");
+    assert_eq!(token_balance(&system, token, CREATOR), 2500);
+    assert!(!revoke(CREATOR, CONTRIBUTOR1, start + 600, false), "A schedule can only be revoked once");
+
+    // An admin burns what would vest after block start + 600
+    let held = token_balance(&system, token, LAUNCHPAD);
+    assert!(revoke(OWNER, CONTRIBUTOR2, start + 600, true), "Admin revocation should succeed");
+    assert_eq!(token_balance(&system, token, LAUNCHPAD), held - 2000);
+    assert_eq!(token_balance(&system, token, CREATOR), 2500);
+
+    // Tokens vested up to the revocation stay claimable
+    advance_to_block(&system, start + 1000);
+    for (beneficiary, vested) in [(CONTRIBUTOR1, 2500), (CONTRIBUTOR2, 3000)] {
+        let msg_id = program.send_bytes(beneficiary, encode_call("Launchpad", "ClaimTeamTokens", launch_id));
+        let result = system.run_next_block();
+        assert!(result.succeed.contains(&msg_id), "ClaimTeamTokens should succeed");
+        assert_eq!(token_balance(&system, token, beneficiary), vested);
+    }
+}
+
+// =============================================================================
+// BATCH PROCESSING TESTS
+// =============================================================================
+
+#[test]
+fn test_distribute_batch_pushes_tokens() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let stablecoin = deploy_stablecoin(&system, &[CONTRIBUTOR1, CONTRIBUTOR2], 1000 * ONE_VARA);
+
+    let mut input = create_test_launch_input(&system);
+    input.payment_token = TokenType::Vft(stablecoin);
+    let price = input.price_per_token;
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR1, launch_id, 60 * ONE_VARA);
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR2, launch_id, 40 * ONE_VARA);
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "DistributeBatch", (launch_id, 0u32, 10u32)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Distribution needs a successful launch");
+
+    advance_blocks(&system, 10000);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Finalize should succeed");
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "DistributeBatch", (launch_id, 0u32, 1u32)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Only a keeper can distribute");
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "DistributeBatch", (launch_id, 0u32, 51u32)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Batch size is bounded");
+
+    // A batch holds only its launch: a second batch waits, claims do not
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    let batch_id = program.send_bytes(OWNER, encode_call("Launchpad", "DistributeBatch", (launch_id, 0u32, 1u32)));
+    let second_id = program.send_bytes(OWNER, encode_call("Launchpad", "DistributeBatch", (launch_id, 0u32, 1u32)));
+    let claim_id = program.send_bytes(CONTRIBUTOR2, encode_call("Launchpad", "ClaimTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&batch_id), "DistributeBatch should succeed");
+    assert!(result.failed.contains(&second_id), "Only one batch per launch at a time");
+    assert!(result.succeed.contains(&claim_id), "Claims are not blocked by a running batch");
+    assert_eq!(token_balance(&system, launch.token_address, CONTRIBUTOR1), 60 * ONE_VARA / price);
+    assert_eq!(token_balance(&system, launch.token_address, CONTRIBUTOR2), 40 * ONE_VARA / price);
+
+    // Pages advance the persisted cursor, which wraps after the last page
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert_eq!(launch.distribution_cursor, 1);
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "DistributeBatch", (launch_id, 0u32, 1u32)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Batch must start at the cursor");
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "DistributeBatch", (launch_id, 1u32, 50u32)));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "DistributeBatch should succeed");
+
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert_eq!(launch.distribution_cursor, 0);
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "DistributeBatch", (launch_id, 1u32, 1u32)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "A stale start should fail");
+
+    // Pushed tokens count as claimed
+    let msg_id = program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "ClaimTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Nothing left to claim");
+}
+
+#[test]
+fn test_refund_batch_pushes_refunds() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let stablecoin = deploy_stablecoin(&system, &[CONTRIBUTOR1, CONTRIBUTOR2], 1000 * ONE_VARA);
+
+    let mut input = create_test_launch_input(&system);
+    input.payment_token = TokenType::Vft(stablecoin);
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR1, launch_id, 30 * ONE_VARA);
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR2, launch_id, 20 * ONE_VARA);
+
+    // Below the soft cap: the launch fails
+    advance_blocks(&system, 10000);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Finalize should succeed");
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "DistributeBatch", (launch_id, 0u32, 10u32)));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Failed launches distribute nothing");
+
+    // A contributor who already claimed is skipped
+    let msg_id = program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "ClaimRefund", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ClaimRefund should succeed");
+
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "RefundBatch", (launch_id, 0u32, 10u32)));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "RefundBatch should succeed");
+
+    assert_eq!(token_balance(&system, stablecoin, CONTRIBUTOR1), 1000 * ONE_VARA);
+    assert_eq!(token_balance(&system, stablecoin, CONTRIBUTOR2), 1000 * ONE_VARA);
+    assert_eq!(token_balance(&system, stablecoin, LAUNCHPAD), 0);
+
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert_eq!(launch.refund_cursor, 0);
+    assert_eq!(launch.status, LaunchStatus::Finalized);
+}
+
+// =============================================================================
+// CLAIM-ON-BEHALF TESTS
+// =============================================================================
+
+#[test]
+fn test_claim_for_beneficiary_and_claim_address() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let stablecoin = deploy_stablecoin(&system, &[CONTRIBUTOR1, CONTRIBUTOR2], 1000 * ONE_VARA);
+
+    let mut input = create_test_launch_input(&system);
+    input.payment_token = TokenType::Vft(stablecoin);
+    let price = input.price_per_token;
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR1, launch_id, 60 * ONE_VARA);
+    contribute_vft(&system, &program, stablecoin, CONTRIBUTOR2, launch_id, 40 * ONE_VARA);
+
+    advance_blocks(&system, 10000);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Finalize should succeed");
+
+    // Zero address is rejected
+    let msg_id = program.send_bytes(
+        CONTRIBUTOR2,
+        encode_call("Launchpad", "SetClaimAddress", Some(ActorId::zero())),
+    );
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Zero claim address should fail");
+
+    let msg_id = program.send_bytes(
+        CONTRIBUTOR2,
+        encode_call("Launchpad", "SetClaimAddress", Some(ActorId::from(NON_WHITELISTED))),
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "SetClaimAddress should succeed");
+
+    let claim_address: Option<ActorId> =
+        query(&system, &program, "GetClaimAddress", ActorId::from(CONTRIBUTOR2));
+    assert_eq!(claim_address, Some(ActorId::from(NON_WHITELISTED)));
+
+    // A relayer claims for both contributors
+    let msg_id = program.send_bytes(
+        ANYONE,
+        encode_call("Launchpad", "ClaimTokensFor", (launch_id, ActorId::from(CONTRIBUTOR1))),
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ClaimTokensFor should succeed");
+
+    let msg_id = program.send_bytes(
+        ANYONE,
+        encode_call("Launchpad", "ClaimTokensFor", (launch_id, ActorId::from(CONTRIBUTOR2))),
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ClaimTokensFor should succeed");
+
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert_eq!(token_balance(&system, launch.token_address, CONTRIBUTOR1), 60 * ONE_VARA / price);
+    assert_eq!(token_balance(&system, launch.token_address, CONTRIBUTOR2), 0);
+    assert_eq!(token_balance(&system, launch.token_address, NON_WHITELISTED), 40 * ONE_VARA / price);
+    assert_eq!(token_balance(&system, launch.token_address, ANYONE), 0);
+
+    // Already claimed on their behalf
+    let msg_id = program.send_bytes(CONTRIBUTOR1, encode_call("Launchpad", "ClaimTokens", launch_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Nothing left to claim");
+
+    // Clearing restores payouts to the contributor
+    let msg_id = program.send_bytes(CONTRIBUTOR2, encode_call("Launchpad", "SetClaimAddress", None::<ActorId>));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "SetClaimAddress should succeed");
+
+    let claim_address: Option<ActorId> =
+        query(&system, &program, "GetClaimAddress", ActorId::from(CONTRIBUTOR2));
+    assert_eq!(claim_address, None);
+}
+
+// =============================================================================
+// TRANSFER LEDGER TESTS
+// =============================================================================
+
+#[test]
+fn test_failed_transfer_recorded_and_retried() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    // Stray token the launchpad does not hold yet
+    let stray = deploy_mock_token(&system, PROJECT_TOKEN, "STRAY", &[ANYONE], 1000 * ONE_VARA);
+
+    // The token rejects the rescue: the transfer is kept in the ledger
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "RescueTokens", (stray, 500 * ONE_VARA)));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "A rejected transfer should not revert the rescue");
+
+    let stuck: Vec<(u64, PendingTransfer)> = query(&system, &program, "GetStuckTransfers", ());
+    assert_eq!(stuck.len(), 1);
+    let (transfer_id, transfer) = stuck[0].clone();
+    assert_eq!(transfer.launch_id, None);
+    assert_eq!(transfer.token, stray);
+    assert_eq!(transfer.to, ActorId::from(OWNER));
+    assert_eq!(transfer.amount, 500 * ONE_VARA);
+    assert_eq!(transfer.status, TransferStatus::Failed);
+    assert_eq!(transfer.attempts, 1);
+
+    // Still short: the retry fails again and stays queued
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "RetryTransfer", transfer_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "RetryTransfer should succeed");
+
+    let transfer: Option<PendingTransfer> = query(&system, &program, "GetPendingTransfer", transfer_id);
+    let transfer = transfer.expect("Transfer should stay in the ledger");
+    assert_eq!(transfer.status, TransferStatus::Failed);
+    assert_eq!(transfer.attempts, 2);
+
+    assert!(
+        token_action(&system, stray, ANYONE, VftAction::Transfer { to: ActorId::from(LAUNCHPAD), value: 500 * ONE_VARA }),
+        "Funding the launchpad should succeed"
+    );
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "RetryTransfer", transfer_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "RetryTransfer should succeed");
+    assert_eq!(token_balance(&system, stray, OWNER), 500 * ONE_VARA);
+
+    // Settled transfers leave the ledger and cannot be re-sent
+    let stuck: Vec<(u64, PendingTransfer)> = query(&system, &program, "GetStuckTransfers", ());
+    assert!(stuck.is_empty());
+    let transfer: Option<PendingTransfer> = query(&system, &program, "GetPendingTransfer", transfer_id);
+    assert_eq!(transfer, None);
+
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "RetryTransfer", transfer_id));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Settled transfers cannot be retried");
+}
+
+// =============================================================================
+// NATIVE PAYOUT TESTS
+// =============================================================================
+
+#[test]
+fn test_undelivered_native_payout_becomes_withdrawable() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let amm = deploy_amm(&system);
+
+    let input = create_test_launch_input(&system);
+    let launch_id = setup_active_launch(&system, &program, input);
+
+    // Excess over max_per_wallet is sent back to the contributor's mailbox
+    let msg_id = program.send_bytes_with_value(
+        CONTRIBUTOR1,
+        encode_call("Launchpad", "Contribute", launch_id),
+        300 * ONE_VARA,
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Contribute should succeed");
+
+    let refund = Log::builder().source(program.id()).dest(CONTRIBUTOR1);
+    let mailbox = system.get_mailbox(CONTRIBUTOR1);
+    assert!(mailbox.contains(&refund), "Excess refund should be sent");
+    let balance_before = system.balance_of(CONTRIBUTOR1);
+    mailbox.claim_value(refund).expect("Claiming the refund should succeed");
+    let received = system.balance_of(CONTRIBUTOR1) - balance_before;
+    assert!((100 * ONE_VARA..101 * ONE_VARA).contains(&received), "Excess should be refunded");
+
+    let pending: u128 = query(&system, &program, "GetPendingWithdrawal", ActorId::from(CONTRIBUTOR1));
+    assert_eq!(pending, 0);
+
+    let msg_id = program.send_bytes_with_value(
+        CONTRIBUTOR2,
+        encode_call("Launchpad", "Contribute", launch_id),
+        200 * ONE_VARA,
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Contribute should succeed");
+
+    advance_blocks(&system, 10000);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Finalize should succeed");
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "WithdrawFunds", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "WithdrawFunds should succeed");
+
+    // The AMM panics on an empty payload, so the fee payment bounces
+    let msg_id = program.send_bytes(OWNER, encode_call("Launchpad", "SetFeeRecipient", amm));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "SetFeeRecipient should succeed");
+
+    let amm_balance = system.balance_of(amm);
+    let msg_id = program.send_bytes(OWNER, encode_call_no_params("Launchpad", "WithdrawFees"));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "WithdrawFees should not fail on a rejected payment");
+    assert_eq!(system.balance_of(amm), amm_balance);
+
+    // 2% of the 400 VARA raise
+    let pending: u128 = query(&system, &program, "GetPendingWithdrawal", amm);
+    assert_eq!(pending, 8 * ONE_VARA);
+
+    let msg_id = program.send_bytes(ANYONE, encode_call_no_params("Launchpad", "WithdrawPending"));
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "Nothing pending for this caller");
+
+    // A refund bounced by a user's claim address is kept for the user
+    let mut input = create_test_launch_input(&system);
+    input.min_raise = 500 * ONE_VARA;
+    let failed_id = setup_active_launch(&system, &program, input);
+
+    let msg_id = program.send_bytes_with_value(
+        CONTRIBUTOR2,
+        encode_call("Launchpad", "Contribute", failed_id),
+        50 * ONE_VARA,
+    );
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Contribute should succeed");
+
+    let msg_id = program.send_bytes(CONTRIBUTOR2, encode_call("Launchpad", "SetClaimAddress", Some(amm)));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "SetClaimAddress should succeed");
+
+    advance_blocks(&system, 10000);
+    let msg_id = program.send_bytes(ANYONE, encode_call("Launchpad", "Finalize", failed_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Finalize should succeed");
+
+    let msg_id = program.send_bytes(CONTRIBUTOR2, encode_call("Launchpad", "ClaimRefund", failed_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "ClaimRefund should succeed");
+    assert_eq!(system.balance_of(amm), amm_balance);
+
+    let pending: u128 = query(&system, &program, "GetPendingWithdrawal", ActorId::from(CONTRIBUTOR2));
+    assert_eq!(pending, 50 * ONE_VARA);
+    let pending: u128 = query(&system, &program, "GetPendingWithdrawal", amm);
+    assert_eq!(pending, 8 * ONE_VARA);
+
+    // The user withdraws it to their own account
+    let msg_id = program.send_bytes(CONTRIBUTOR2, encode_call_no_params("Launchpad", "WithdrawPending"));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "WithdrawPending should succeed");
+
+    let payout = Log::builder().source(program.id()).dest(CONTRIBUTOR2);
+    let mailbox = system.get_mailbox(CONTRIBUTOR2);
+    assert!(mailbox.contains(&payout), "Pending payout should be sent");
+    let balance_before = system.balance_of(CONTRIBUTOR2);
+    mailbox.claim_value(payout).expect("Claiming the payout should succeed");
+    let received = system.balance_of(CONTRIBUTOR2) - balance_before;
+    assert!((50 * ONE_VARA..51 * ONE_VARA).contains(&received), "Pending payout should be received");
+
+    let pending: u128 = query(&system, &program, "GetPendingWithdrawal", ActorId::from(CONTRIBUTOR2));
+    assert_eq!(pending, 0);
+}
+
+// =============================================================================
+// REJECTED CONTRIBUTION TESTS
+// =============================================================================
+
+/// Send `value` with `payload`, expect the message to fail and the value to
+/// come back: only gas is spent and the launchpad keeps nothing.
+fn assert_value_returned(system: &System, program: &Program<'_>, from: u64, payload: Vec<u8>, value: u128, reason: &str) {
+    let sender_before = system.balance_of(from);
+    let launchpad_before = system.balance_of(program.id());
+
+    let msg_id = program.send_bytes_with_value(from, payload, value);
+    let result = system.run_next_block();
+    assert!(result.failed.contains(&msg_id), "{reason} should be rejected");
+
+    assert_eq!(system.balance_of(program.id()), launchpad_before, "{reason}: launchpad kept value");
+    let spent = sender_before - system.balance_of(from);
+    assert!(spent < ONE_VARA, "{reason}: value not returned (spent {spent})");
+}
+
+#[test]
+fn test_rejected_contributions_return_value() {
+    let system = setup_system();
+    let program = deploy_contract(&system);
+    let stablecoin = deploy_stablecoin(&system, &[CONTRIBUTOR1], 1000 * ONE_VARA);
+
+    let contribute = |launch_id: u64| encode_call("Launchpad", "Contribute", launch_id);
+
+    assert_value_returned(&system, &program, CONTRIBUTOR1, contribute(99), 50 * ONE_VARA, "Unknown launch");
+
+    // Pending, then active but before start_time
+    let input = create_test_launch_input(&system);
+    let end = input.end_time;
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "CreateLaunch", input));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "CreateLaunch should succeed");
+    let launch_id: u64 = 0;
+
+    assert_value_returned(&system, &program, CONTRIBUTOR1, contribute(launch_id), 50 * ONE_VARA, "Pending launch");
+
+    let msg_id = program.send_bytes(CREATOR, encode_call("Launchpad", "StartLaunch", launch_id));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "StartLaunch should succeed");
+
+    assert_value_returned(&system, &program, CONTRIBUTOR1, contribute(launch_id), 50 * ONE_VARA, "Before start_time");
+
+    advance_blocks(&system, 15);
+
+    assert_value_returned(&system, &program, CONTRIBUTOR1, contribute(launch_id), ONE_VARA / 10_000, "Below one token");
+
+    // Wallet cap reached
+    let msg_id = program.send_bytes_with_value(CONTRIBUTOR1, contribute(launch_id), 200 * ONE_VARA);
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Contribute should succeed");
+
+    assert_value_returned(&system, &program, CONTRIBUTOR1, contribute(launch_id), 50 * ONE_VARA, "Exhausted allocation");
+
+    // Paused contract
+    let msg_id = program.send_bytes(OWNER, encode_call_no_params("Launchpad", "Pause"));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Pause should succeed");
+
+    assert_value_returned(&system, &program, CONTRIBUTOR2, contribute(launch_id), 50 * ONE_VARA, "Paused contract");
+
+    let msg_id = program.send_bytes(OWNER, encode_call_no_params("Launchpad", "Resume"));
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Resume should succeed");
+
+    // Whitelist without the contributor
+    let mut whitelisted = create_test_launch_input(&system);
+    whitelisted.whitelist_enabled = true;
+    let whitelisted_id = setup_active_launch(&system, &program, whitelisted);
+
+    assert_value_returned(&system, &program, NON_WHITELISTED, contribute(whitelisted_id), 50 * ONE_VARA, "Not whitelisted");
+
+    // Sold out
+    let mut small = create_test_launch_input(&system);
+    small.max_raise = 200 * ONE_VARA;
+    let small_id = setup_active_launch(&system, &program, small);
+
+    let msg_id = program.send_bytes_with_value(CONTRIBUTOR1, contribute(small_id), 200 * ONE_VARA);
+    let result = system.run_next_block();
+    assert!(result.succeed.contains(&msg_id), "Contribute should succeed");
+
+    assert_value_returned(&system, &program, CONTRIBUTOR2, contribute(small_id), 50 * ONE_VARA, "Fully subscribed");
+
+    // Phase eligibility and gaps between phases
+    let mut phased = create_test_launch_input(&system);
+    let start = phased.start_time;
+    phased.phases = vec![
+        SalePhase {
+            name: "Seed".into(),
+            start_time: start,
+            end_time: start + 30,
+            price_per_token: ONE_VARA / 2000,
+            max_per_wallet: 20 * ONE_VARA,
+            eligibility: PhaseEligibility::Addresses([ActorId::from(CONTRIBUTOR1)].into()),
+        },
+        SalePhase {
+            name: "Public".into(),
+            start_time: start + 40,
+            end_time: phased.end_time,
+            price_per_token: ONE_VARA / 1000,
+            max_per_wallet: 100 * ONE_VARA,
+            eligibility: PhaseEligibility::Public,
+        },
+    ];
+    let phased_id = setup_active_launch(&system, &program, phased);
+
+    assert_value_returned(&system, &program, CONTRIBUTOR2, contribute(phased_id), 10 * ONE_VARA, "Ineligible for phase");
+
+    advance_to_block(&system, start + 35);
+    assert_value_returned(&system, &program, CONTRIBUTOR1, contribute(phased_id), 10 * ONE_VARA, "Between phases");
+
+    // Wrong currency, either way round
+    let mut vft = create_test_launch_input(&system);
+    vft.payment_token = TokenType::Vft(stablecoin);
+    let vft_id = setup_active_launch(&system, &program, vft);
+
+    assert_value_returned(&system, &program, CONTRIBUTOR1, contribute(vft_id), 50 * ONE_VARA, "VFT-denominated launch");
+
+    approve_launchpad(&system, stablecoin, CONTRIBUTOR1, 50 * ONE_VARA);
+    assert_value_returned(
+        &system,
+        &program,
+        CONTRIBUTOR1,
+        encode_call("Launchpad", "ContributeVft", (vft_id, 50 * ONE_VARA)),
+        50 * ONE_VARA,
+        "Native value with a VFT contribution",
+    );
+    assert_eq!(token_balance(&system, stablecoin, CONTRIBUTOR1), 1000 * ONE_VARA);
+
+    // After end_time
+    advance_to_block(&system, end + 1);
+    assert_value_returned(&system, &program, CONTRIBUTOR2, contribute(launch_id), 50 * ONE_VARA, "After end_time");
+
+    // Nothing was recorded for any rejected contribution
+    let launch: Launch = query::<_, Option<Launch>>(&system, &program, "GetLaunch", launch_id).unwrap();
+    assert_eq!(launch.total_raised, 200 * ONE_VARA);
+    assert_eq!(launch.contributors, vec![ActorId::from(CONTRIBUTOR1)]);
+}
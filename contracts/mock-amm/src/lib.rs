@@ -0,0 +1,185 @@
+//! Stand-in AMM for launchpad liquidity tests.
+//!
+//! Speaks the raw SCALE protocol used by the launchpad's `DexClient`:
+//! - one constant-product pool per `(token, quote)` pair, `quote: None` being native VARA
+//! - `AddLiquidity` pulls both sides with VFT `TransferFrom` (native VARA must be
+//!   attached as value) and replies with the SCALE-encoded liquidity minted
+//! - `TransferLiquidity` moves pool shares and replies with `()`
+//! - `DexQuery` payloads reply with the SCALE-encoded answer
+//!
+//! Failed actions panic, so the caller receives an error reply and its value back.
+
+#![no_std]
+
+extern crate alloc;
+
+use gstd::ActorId;
+use parity_scale_codec::{Decode, Encode};
+
+/// Token amount type (mirrors the launchpad's `U256` alias).
+pub type U256 = u128;
+
+/// State-changing pool messages.
+#[derive(Debug, Clone, Encode, Decode)]
+pub enum DexAction {
+    AddLiquidity {
+        token: ActorId,
+        quote: Option<ActorId>,
+        token_amount: U256,
+        quote_amount: U256,
+        to: ActorId,
+    },
+    TransferLiquidity {
+        token: ActorId,
+        quote: Option<ActorId>,
+        to: ActorId,
+        amount: U256,
+    },
+}
+
+/// Read-only pool messages.
+#[derive(Debug, Clone, Encode, Decode)]
+pub enum DexQuery {
+    /// Replies `(token_reserve, quote_reserve)`.
+    Reserves { token: ActorId, quote: Option<ActorId> },
+    LiquidityOf { token: ActorId, quote: Option<ActorId>, account: ActorId },
+}
+
+#[cfg(feature = "wasm-binary")]
+#[cfg(not(target_arch = "wasm32"))]
+pub use code::WASM_BINARY_OPT as WASM_BINARY;
+
+#[cfg(feature = "wasm-binary")]
+#[cfg(not(target_arch = "wasm32"))]
+mod code {
+    include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
+}
+
+#[cfg(target_arch = "wasm32")]
+mod wasm {
+    use super::*;
+    use alloc::collections::BTreeMap;
+    use alloc::vec::Vec;
+    use gstd::{exec, msg};
+    use parity_scale_codec::DecodeAll;
+
+    /// Subset of the VFT protocol needed to pull deposits (variant order matters).
+    #[derive(Encode)]
+    #[allow(dead_code)]
+    enum VftAction {
+        Transfer { to: ActorId, value: U256 },
+        TransferFrom { from: ActorId, to: ActorId, value: U256 },
+    }
+
+    #[derive(Default)]
+    struct Pool {
+        token_reserve: U256,
+        quote_reserve: U256,
+        total_liquidity: U256,
+        liquidity: BTreeMap<ActorId, U256>,
+    }
+
+    type PoolKey = (ActorId, Option<ActorId>);
+
+    static mut POOLS: Option<BTreeMap<PoolKey, Pool>> = None;
+
+    #[allow(static_mut_refs)]
+    fn pools() -> &'static mut BTreeMap<PoolKey, Pool> {
+        unsafe { POOLS.get_or_insert_with(BTreeMap::new) }
+    }
+
+    fn sqrt(value: U256) -> U256 {
+        if value < 2 {
+            return value;
+        }
+        let mut x = value;
+        let mut y = (x + 1) / 2;
+        while y < x {
+            x = y;
+            y = (x + value / x) / 2;
+        }
+        x
+    }
+
+    async fn pull(token: ActorId, from: ActorId, value: U256) {
+        let action = VftAction::TransferFrom { from, to: exec::program_id(), value };
+        msg::send_bytes_for_reply(token, action.encode(), 0, 0)
+            .expect("Failed to send TransferFrom")
+            .await
+            .expect("TransferFrom failed");
+    }
+
+    async fn add_liquidity(
+        token: ActorId,
+        quote: Option<ActorId>,
+        token_amount: U256,
+        quote_amount: U256,
+        to: ActorId,
+    ) -> U256 {
+        let source = msg::source();
+        match quote {
+            Some(quote_token) => pull(quote_token, source, quote_amount).await,
+            None => assert_eq!(msg::value(), quote_amount, "Attached value must match quote amount"),
+        }
+        pull(token, source, token_amount).await;
+
+        let pool = pools().entry((token, quote)).or_default();
+        let minted = if pool.total_liquidity == 0 {
+            sqrt(token_amount * quote_amount)
+        } else {
+            (token_amount * pool.total_liquidity / pool.token_reserve)
+                .min(quote_amount * pool.total_liquidity / pool.quote_reserve)
+        };
+        assert!(minted > 0, "Insufficient liquidity minted");
+
+        pool.token_reserve += token_amount;
+        pool.quote_reserve += quote_amount;
+        pool.total_liquidity += minted;
+        *pool.liquidity.entry(to).or_default() += minted;
+        minted
+    }
+
+    fn transfer_liquidity(token: ActorId, quote: Option<ActorId>, to: ActorId, amount: U256) {
+        let pool = pools().get_mut(&(token, quote)).expect("Unknown pool");
+        let balance = pool.liquidity.entry(msg::source()).or_default();
+        *balance = balance.checked_sub(amount).expect("Insufficient liquidity");
+        *pool.liquidity.entry(to).or_default() += amount;
+    }
+
+    fn query(query: DexQuery) -> Vec<u8> {
+        match query {
+            DexQuery::Reserves { token, quote } => pools()
+                .get(&(token, quote))
+                .map(|pool| (pool.token_reserve, pool.quote_reserve))
+                .unwrap_or_default()
+                .encode(),
+            DexQuery::LiquidityOf { token, quote, account } => pools()
+                .get(&(token, quote))
+                .and_then(|pool| pool.liquidity.get(&account).copied())
+                .unwrap_or(0)
+                .encode(),
+        }
+    }
+
+    #[gstd::async_main]
+    async fn main() {
+        let payload = msg::load_bytes().expect("Invalid payload");
+
+        let reply = if let Ok(action) = DexAction::decode_all(&mut payload.as_slice()) {
+            match action {
+                DexAction::AddLiquidity { token, quote, token_amount, quote_amount, to } => {
+                    add_liquidity(token, quote, token_amount, quote_amount, to).await.encode()
+                }
+                DexAction::TransferLiquidity { token, quote, to, amount } => {
+                    transfer_liquidity(token, quote, to, amount);
+                    Vec::new()
+                }
+            }
+        } else {
+            let dex_query = DexQuery::decode_all(&mut payload.as_slice()).expect("Unknown message");
+            query(dex_query)
+        };
+
+        msg::reply_bytes(reply, 0).expect("Failed to reply");
+    }
+}
@@ -0,0 +1,171 @@
+//! Mock VFT token for launchpad integration tests.
+//!
+//! Speaks the raw SCALE protocol used by the launchpad's `VftClient`:
+//! - init payload is `VftInitParams` (the whole supply is minted to `initial_owner`)
+//! - an empty payload is a readiness ping and gets an empty reply
+//! - `VftAction` payloads mutate balances and reply with `()`
+//! - `VftQuery` payloads reply with the SCALE-encoded answer
+//!
+//! Failed actions (insufficient balance or allowance) panic, so the caller
+//! receives an error reply.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use gstd::ActorId;
+use parity_scale_codec::{Decode, Encode};
+
+/// Token amount type (mirrors the launchpad's `U256` alias).
+pub type U256 = u128;
+
+/// Token initialization parameters.
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct VftInitParams {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub total_supply: U256,
+    pub initial_owner: ActorId,
+}
+
+/// State-changing token messages.
+#[derive(Debug, Clone, Encode, Decode)]
+pub enum VftAction {
+    Transfer { to: ActorId, value: U256 },
+    TransferFrom { from: ActorId, to: ActorId, value: U256 },
+    Approve { spender: ActorId, value: U256 },
+    Mint { to: ActorId, value: U256 },
+    Burn { from: ActorId, value: U256 },
+}
+
+/// Read-only token messages.
+#[derive(Debug, Clone, Encode, Decode)]
+pub enum VftQuery {
+    BalanceOf { account: ActorId },
+    Allowance { owner: ActorId, spender: ActorId },
+    TotalSupply,
+    Name,
+    Symbol,
+    Decimals,
+}
+
+#[cfg(feature = "wasm-binary")]
+#[cfg(not(target_arch = "wasm32"))]
+pub use code::WASM_BINARY_OPT as WASM_BINARY;
+
+#[cfg(feature = "wasm-binary")]
+#[cfg(not(target_arch = "wasm32"))]
+mod code {
+    include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
+}
+
+#[cfg(target_arch = "wasm32")]
+mod wasm {
+    use super::*;
+    use alloc::collections::BTreeMap;
+    use alloc::vec::Vec;
+    use gstd::msg;
+    use parity_scale_codec::DecodeAll;
+
+    #[derive(Default)]
+    struct Token {
+        name: String,
+        symbol: String,
+        decimals: u8,
+        total_supply: U256,
+        balances: BTreeMap<ActorId, U256>,
+        allowances: BTreeMap<(ActorId, ActorId), U256>,
+    }
+
+    impl Token {
+        fn debit(&mut self, from: ActorId, value: U256) {
+            let balance = self.balances.entry(from).or_default();
+            *balance = balance.checked_sub(value).expect("Insufficient balance");
+        }
+
+        fn credit(&mut self, to: ActorId, value: U256) {
+            *self.balances.entry(to).or_default() += value;
+        }
+
+        fn apply(&mut self, source: ActorId, action: VftAction) {
+            match action {
+                VftAction::Transfer { to, value } => {
+                    self.debit(source, value);
+                    self.credit(to, value);
+                }
+                VftAction::TransferFrom { from, to, value } => {
+                    let allowance = self.allowances.entry((from, source)).or_default();
+                    *allowance = allowance.checked_sub(value).expect("Insufficient allowance");
+                    self.debit(from, value);
+                    self.credit(to, value);
+                }
+                VftAction::Approve { spender, value } => {
+                    self.allowances.insert((source, spender), value);
+                }
+                VftAction::Mint { to, value } => {
+                    self.total_supply += value;
+                    self.credit(to, value);
+                }
+                VftAction::Burn { from, value } => {
+                    self.debit(from, value);
+                    self.total_supply -= value;
+                }
+            }
+        }
+
+        fn query(&self, query: VftQuery) -> Vec<u8> {
+            match query {
+                VftQuery::BalanceOf { account } => {
+                    self.balances.get(&account).copied().unwrap_or(0).encode()
+                }
+                VftQuery::Allowance { owner, spender } => self
+                    .allowances
+                    .get(&(owner, spender))
+                    .copied()
+                    .unwrap_or(0)
+                    .encode(),
+                VftQuery::TotalSupply => self.total_supply.encode(),
+                VftQuery::Name => self.name.encode(),
+                VftQuery::Symbol => self.symbol.encode(),
+                VftQuery::Decimals => self.decimals.encode(),
+            }
+        }
+    }
+
+    static mut TOKEN: Option<Token> = None;
+
+    #[allow(static_mut_refs)]
+    fn token() -> &'static mut Token {
+        unsafe { TOKEN.get_or_insert_with(Token::default) }
+    }
+
+    #[no_mangle]
+    extern "C" fn init() {
+        let params: VftInitParams = msg::load().expect("Invalid init payload");
+        let token = token();
+        token.name = params.name;
+        token.symbol = params.symbol;
+        token.decimals = params.decimals;
+        token.total_supply = params.total_supply;
+        token.credit(params.initial_owner, params.total_supply);
+    }
+
+    #[no_mangle]
+    extern "C" fn handle() {
+        let payload = msg::load_bytes().expect("Invalid payload");
+
+        let reply = if payload.is_empty() {
+            Vec::new()
+        } else if let Ok(action) = VftAction::decode_all(&mut payload.as_slice()) {
+            token().apply(msg::source(), action);
+            Vec::new()
+        } else {
+            let query = VftQuery::decode_all(&mut payload.as_slice()).expect("Unknown message");
+            token().query(query)
+        };
+
+        msg::reply_bytes(reply, 0).expect("Failed to reply");
+    }
+}
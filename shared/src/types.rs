@@ -1,6 +1,8 @@
 //! Common types used across contracts.
 
 use alloc::string::String;
+use alloc::vec::Vec;
+use crate::errors::ContractError;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use sails_rs::prelude::*;
@@ -31,11 +33,17 @@ impl RoleId {
     pub const DEFAULT_ADMIN: Self = Self([0u8; 32]);
 
     /// Create a new role from a string identifier.
-    pub fn from_str(s: &str) -> Self {
+    ///
+    /// Usable in constants; identifiers longer than 32 bytes are truncated.
+    #[allow(clippy::should_implement_trait)]
+    pub const fn from_str(s: &str) -> Self {
         let mut bytes = [0u8; 32];
         let s_bytes = s.as_bytes();
-        let len = s_bytes.len().min(32);
-        bytes[..len].copy_from_slice(&s_bytes[..len]);
+        let mut i = 0;
+        while i < s_bytes.len() && i < 32 {
+            bytes[i] = s_bytes[i];
+            i += 1;
+        }
         Self(bytes)
     }
 }
@@ -117,20 +125,42 @@ impl Default for TokenType {
     }
 }
 
+/// Maximum number of points in a custom vesting schedule.
+pub const MAX_VESTING_POINTS: usize = 48;
+
+/// How the tokens left after the TGE unlock are released.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq, Default)]
+#[codec(crate = sails_rs::scale_codec)]
+#[scale_info(crate = sails_rs::scale_info)]
+pub enum VestingCurve {
+    /// Continuous release from `start_block` to the vesting end.
+    #[default]
+    Linear,
+    /// Linear release in steps every `interval` blocks from `start_block`.
+    Stepped { interval: BlockNumber },
+    /// Cumulative `(block, bps)` unlock points; the last must reach 10000.
+    Custom(Vec<(BlockNumber, BasisPoints)>),
+}
+
 /// Configuration for vesting schedules.
 #[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
 #[codec(crate = sails_rs::scale_codec)]
 #[scale_info(crate = sails_rs::scale_info)]
 pub struct VestingConfig {
-    /// Block when vesting starts.
+    /// Block when vesting starts (the TGE).
     pub start_block: BlockNumber,
-    /// Duration of cliff period in blocks (no tokens released).
+    /// Duration of cliff period in blocks (only the TGE unlock is released).
     pub cliff_duration: BlockNumber,
     /// Total vesting duration in blocks (including cliff).
     pub vesting_duration: BlockNumber,
+    /// Share released at `start_block`, in basis points.
+    pub tge_unlock_bps: BasisPoints,
+    /// Release shape for the rest.
+    pub curve: VestingCurve,
 }
 
 impl VestingConfig {
+    /// Cliff-plus-linear schedule without a TGE unlock.
     pub fn new(
         start_block: BlockNumber,
         cliff_duration: BlockNumber,
@@ -140,16 +170,60 @@ impl VestingConfig {
             start_block,
             cliff_duration,
             vesting_duration,
+            tge_unlock_bps: 0,
+            curve: VestingCurve::Linear,
         }
     }
 
+    /// Check the TGE share and curve are well-formed.
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if self.tge_unlock_bps > MAX_BASIS_POINTS {
+            return Err(ContractError::invalid_input("TGE unlock must be at most 10000 bps"));
+        }
+
+        match self.curve {
+            VestingCurve::Linear => {}
+            VestingCurve::Stepped { interval } => {
+                if interval == 0 || interval > self.vesting_duration {
+                    return Err(ContractError::invalid_input("Vesting step must be within the duration"));
+                }
+            }
+            VestingCurve::Custom(ref points) => {
+                if points.is_empty() || points.len() > MAX_VESTING_POINTS {
+                    return Err(ContractError::invalid_input("Custom schedule needs 1-48 points"));
+                }
+                let mut previous: Option<(BlockNumber, BasisPoints)> = None;
+                for &(block, bps) in points {
+                    let ordered = match previous {
+                        Some((prev_block, prev_bps)) => block > prev_block && bps >= prev_bps,
+                        None => block >= self.start_block,
+                    };
+                    if !ordered || bps > MAX_BASIS_POINTS {
+                        return Err(ContractError::invalid_input("Custom schedule must be increasing"));
+                    }
+                    previous = Some((block, bps));
+                }
+                if previous.map(|(_, bps)| bps) != Some(MAX_BASIS_POINTS) {
+                    return Err(ContractError::invalid_input("Custom schedule must end at 10000 bps"));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns the block when the cliff ends.
     pub fn cliff_end(&self) -> BlockNumber {
         self.start_block.saturating_add(self.cliff_duration)
     }
 
-    /// Returns the block when vesting ends.
+    /// Returns the block when vesting ends (the last point of a custom schedule).
     pub fn vesting_end(&self) -> BlockNumber {
-        self.start_block.saturating_add(self.vesting_duration)
+        match self.curve {
+            VestingCurve::Custom(ref points) => points
+                .last()
+                .map_or(self.start_block, |&(block, _)| block),
+            _ => self.start_block.saturating_add(self.vesting_duration),
+        }
     }
 }